@@ -0,0 +1,20 @@
+PRAGMA foreign_keys = ON;
+
+-- Track when catalog prices first appear for a set so new releases can raise a notification.
+ALTER TABLE card_data_sets ADD COLUMN prices_first_seen_at TEXT;
+
+CREATE TABLE IF NOT EXISTS system_data_sync_notifications (
+  id TEXT PRIMARY KEY,
+  kind TEXT NOT NULL,
+  title TEXT NOT NULL,
+  body TEXT,
+  payload_json TEXT,
+  created_at TEXT NOT NULL,
+  read_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_system_data_sync_notifications_time
+  ON system_data_sync_notifications(created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_card_data_sets_released
+  ON card_data_sets(released_at);
@@ -0,0 +1,11 @@
+PRAGMA foreign_keys = ON;
+
+-- Parent/child set links from Scryfall (`parent_set_code`, e.g. tokens/promos -> main set).
+ALTER TABLE card_data_sets ADD COLUMN parent_set_code TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_card_data_sets_parent
+  ON card_data_sets(parent_set_code);
+
+-- Clear release dates/types that were never populated so repair can distinguish missing from empty.
+UPDATE card_data_sets SET set_type = NULL WHERE trim(IFNULL(set_type, '')) = '';
+UPDATE card_data_sets SET released_at = NULL WHERE trim(IFNULL(released_at, '')) = '';
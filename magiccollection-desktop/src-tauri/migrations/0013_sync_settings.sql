@@ -0,0 +1,8 @@
+PRAGMA foreign_keys = ON;
+
+-- Key/value settings for sync (e.g. the trusted catalog publisher key).
+CREATE TABLE IF NOT EXISTS system_data_sync_settings (
+  key TEXT PRIMARY KEY,
+  value TEXT,
+  updated_at TEXT NOT NULL
+);
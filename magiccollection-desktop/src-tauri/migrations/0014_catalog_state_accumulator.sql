@@ -0,0 +1,6 @@
+PRAGMA foreign_keys = ON;
+
+-- Order-independent sum of per-row price digests for a catalog version (hex, 32 bytes).
+-- Lets patch applies update the state hash from the rows they touch instead of rescanning.
+-- NULL means unknown; the next hash computation rebuilds it from the version's rows.
+ALTER TABLE system_data_sync_dataset_versions ADD COLUMN state_accumulator TEXT;
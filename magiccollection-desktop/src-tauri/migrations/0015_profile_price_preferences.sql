@@ -0,0 +1,6 @@
+PRAGMA foreign_keys = ON;
+
+-- Per-profile price channel (`list_price_channels` id) and valuation currency.
+-- NULL means the app default (tcg-market / USD).
+ALTER TABLE collection_data_profiles ADD COLUMN price_source_id TEXT;
+ALTER TABLE collection_data_profiles ADD COLUMN valuation_currency TEXT;
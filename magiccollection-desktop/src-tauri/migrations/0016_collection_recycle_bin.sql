@@ -0,0 +1,28 @@
+PRAGMA foreign_keys = ON;
+
+-- Recycle bin for removed owned items. Each row is a snapshot of the item at removal time;
+-- rows removed by one command share a batch_id and are purged after expires_at.
+CREATE TABLE IF NOT EXISTS collection_data_removed_items (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  batch_id TEXT NOT NULL,
+  owned_item_id TEXT NOT NULL,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
+  condition_code TEXT NOT NULL DEFAULT 'NM',
+  language TEXT NOT NULL DEFAULT 'en',
+  purchase_price NUMERIC,
+  acquired_at TEXT,
+  location_name TEXT,
+  notes TEXT,
+  tags_json TEXT NOT NULL DEFAULT '[]',
+  created_at TEXT NOT NULL,
+  removed_at TEXT NOT NULL,
+  expires_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_collection_data_removed_items_collection
+  ON collection_data_removed_items(collection_id, removed_at);
+CREATE INDEX IF NOT EXISTS idx_collection_data_removed_items_expires
+  ON collection_data_removed_items(expires_at);
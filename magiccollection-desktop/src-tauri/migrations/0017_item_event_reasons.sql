@@ -0,0 +1,8 @@
+PRAGMA foreign_keys = ON;
+
+-- Why a quantity changed (sold, traded, lost, opened-from-pack, correction). NULL when no reason
+-- was given.
+ALTER TABLE collection_data_item_events ADD COLUMN reason_code TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_collection_data_item_events_reason
+  ON collection_data_item_events(collection_id, reason_code, occurred_at DESC);
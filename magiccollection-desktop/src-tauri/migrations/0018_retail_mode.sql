@@ -0,0 +1,53 @@
+PRAGMA foreign_keys = ON;
+
+-- Opt-in retail (store inventory) mode per profile, asking prices per owned item, customer holds
+-- and recorded sales. Sale lines keep printing and price so history survives the item being sold out.
+ALTER TABLE collection_data_profiles ADD COLUMN retail_mode_enabled INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE collection_data_collection_items ADD COLUMN asking_price NUMERIC;
+
+CREATE TABLE IF NOT EXISTS collection_data_reservations (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  collection_item_id TEXT NOT NULL REFERENCES collection_data_collection_items(id) ON DELETE CASCADE,
+  customer_name TEXT NOT NULL,
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
+  note TEXT,
+  status TEXT NOT NULL DEFAULT 'active',
+  sale_id TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS collection_data_sales (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  customer_name TEXT,
+  note TEXT,
+  currency TEXT NOT NULL,
+  total_amount NUMERIC NOT NULL DEFAULT 0,
+  sold_at TEXT NOT NULL,
+  created_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS collection_data_sale_lines (
+  id TEXT PRIMARY KEY,
+  sale_id TEXT NOT NULL REFERENCES collection_data_sales(id) ON DELETE CASCADE,
+  collection_item_id TEXT REFERENCES collection_data_collection_items(id) ON DELETE SET NULL,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  condition_code TEXT NOT NULL DEFAULT 'NM',
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
+  unit_price NUMERIC NOT NULL,
+  line_total NUMERIC NOT NULL,
+  reservation_id TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_collection_data_reservations_item
+  ON collection_data_reservations(collection_item_id, status);
+CREATE INDEX IF NOT EXISTS idx_collection_data_reservations_collection
+  ON collection_data_reservations(collection_id, status, created_at);
+CREATE INDEX IF NOT EXISTS idx_collection_data_sales_collection
+  ON collection_data_sales(collection_id, sold_at);
+CREATE INDEX IF NOT EXISTS idx_collection_data_sale_lines_sale
+  ON collection_data_sale_lines(sale_id);
@@ -0,0 +1,5 @@
+PRAGMA foreign_keys = ON;
+
+-- Per-profile pricing rules (asking and trade rule sets) as JSON. NULL uses the defaults:
+-- profile price channel, no markup, no floor, no rounding, no condition discounts.
+ALTER TABLE collection_data_profiles ADD COLUMN pricing_rules_json TEXT;
@@ -0,0 +1,41 @@
+PRAGMA foreign_keys = ON;
+
+-- Kits: named bundles of specific owned copies (plus free-text items such as sleeves) that are
+-- checked out and back in as a unit. A checkout row with checked_in_at NULL means the kit is out.
+CREATE TABLE IF NOT EXISTS collection_data_kits (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  description TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS collection_data_kit_items (
+  id TEXT PRIMARY KEY,
+  kit_id TEXT NOT NULL REFERENCES collection_data_kits(id) ON DELETE CASCADE,
+  collection_item_id TEXT REFERENCES collection_data_collection_items(id) ON DELETE SET NULL,
+  label TEXT,
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS collection_data_kit_checkouts (
+  id TEXT PRIMARY KEY,
+  kit_id TEXT NOT NULL REFERENCES collection_data_kits(id) ON DELETE CASCADE,
+  borrower_name TEXT NOT NULL,
+  note TEXT,
+  checked_out_at TEXT NOT NULL,
+  due_at TEXT,
+  checked_in_at TEXT
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_collection_data_kits_collection_name
+  ON collection_data_kits(collection_id, name);
+CREATE INDEX IF NOT EXISTS idx_collection_data_kit_items_kit
+  ON collection_data_kit_items(kit_id);
+CREATE INDEX IF NOT EXISTS idx_collection_data_kit_items_item
+  ON collection_data_kit_items(collection_item_id);
+CREATE INDEX IF NOT EXISTS idx_collection_data_kit_checkouts_kit
+  ON collection_data_kit_checkouts(kit_id, checked_in_at);
@@ -0,0 +1,24 @@
+PRAGMA foreign_keys = ON;
+
+-- Files attached to an owned row (photos of alters, misprints, signed copies). The file lives in
+-- the attachments/ folder next to the database; file_name is relative to that folder.
+CREATE TABLE IF NOT EXISTS collection_data_attachments (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  collection_item_id TEXT NOT NULL REFERENCES collection_data_collection_items(id) ON DELETE CASCADE,
+  kind TEXT NOT NULL DEFAULT 'photo',
+  file_name TEXT NOT NULL,
+  original_name TEXT,
+  mime_type TEXT,
+  byte_size INTEGER NOT NULL DEFAULT 0,
+  sha256 TEXT,
+  caption TEXT,
+  is_primary_image INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_collection_data_attachments_item
+  ON collection_data_attachments(collection_item_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_collection_data_attachments_primary_image
+  ON collection_data_attachments(collection_item_id)
+  WHERE is_primary_image = 1;
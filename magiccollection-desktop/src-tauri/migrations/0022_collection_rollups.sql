@@ -0,0 +1,166 @@
+PRAGMA foreign_keys = ON;
+
+-- Per-collection rollups kept current by triggers on collection_data_collection_items, so every
+-- write path (adds, edits, merges, removals, imports) updates them in the same transaction.
+-- Copy counts are exact; value rollups are per printing and channel and are recomputed lazily
+-- for printings listed in collection_data_rollup_dirty, or for all of them when price_marker no
+-- longer matches MAX(card_data_card_prices.id).
+CREATE TABLE IF NOT EXISTS collection_data_rollups (
+  collection_id TEXT PRIMARY KEY REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  total_cards INTEGER NOT NULL DEFAULT 0,
+  total_foils INTEGER NOT NULL DEFAULT 0,
+  row_count INTEGER NOT NULL DEFAULT 0,
+  price_marker INTEGER NOT NULL DEFAULT -1
+);
+
+CREATE TABLE IF NOT EXISTS collection_data_set_rollups (
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  set_code TEXT NOT NULL,
+  total_cards INTEGER NOT NULL DEFAULT 0,
+  total_foils INTEGER NOT NULL DEFAULT 0,
+  row_count INTEGER NOT NULL DEFAULT 0,
+  PRIMARY KEY (collection_id, set_code)
+);
+
+CREATE TABLE IF NOT EXISTS collection_data_value_rollups (
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL,
+  channel_id TEXT NOT NULL,
+  total_value REAL NOT NULL DEFAULT 0,
+  PRIMARY KEY (collection_id, printing_id, channel_id)
+);
+
+CREATE TABLE IF NOT EXISTS collection_data_rollup_dirty (
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL,
+  PRIMARY KEY (collection_id, printing_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_collection_data_value_rollups_channel
+  ON collection_data_value_rollups(collection_id, channel_id);
+
+DROP TRIGGER IF EXISTS trg_collection_items_ai_rollups;
+DROP TRIGGER IF EXISTS trg_collection_items_ad_rollups;
+DROP TRIGGER IF EXISTS trg_collection_items_au_rollups;
+
+CREATE TRIGGER trg_collection_items_ai_rollups
+AFTER INSERT ON collection_data_collection_items
+BEGIN
+  INSERT INTO collection_data_rollups (collection_id, total_cards, total_foils, row_count)
+  VALUES (
+    NEW.collection_id,
+    NEW.quantity_nonfoil + NEW.quantity_foil,
+    NEW.quantity_foil,
+    CASE WHEN NEW.quantity_nonfoil + NEW.quantity_foil > 0 THEN 1 ELSE 0 END
+  )
+  ON CONFLICT(collection_id) DO UPDATE SET
+    total_cards = total_cards + excluded.total_cards,
+    total_foils = total_foils + excluded.total_foils,
+    row_count = row_count + excluded.row_count;
+  INSERT INTO collection_data_set_rollups (collection_id, set_code, total_cards, total_foils, row_count)
+  VALUES (
+    NEW.collection_id,
+    IFNULL((SELECT set_code FROM card_data_printings WHERE id = NEW.printing_id), ''),
+    NEW.quantity_nonfoil + NEW.quantity_foil,
+    NEW.quantity_foil,
+    CASE WHEN NEW.quantity_nonfoil + NEW.quantity_foil > 0 THEN 1 ELSE 0 END
+  )
+  ON CONFLICT(collection_id, set_code) DO UPDATE SET
+    total_cards = total_cards + excluded.total_cards,
+    total_foils = total_foils + excluded.total_foils,
+    row_count = row_count + excluded.row_count;
+  INSERT OR IGNORE INTO collection_data_rollup_dirty (collection_id, printing_id)
+  VALUES (NEW.collection_id, NEW.printing_id);
+END;
+
+CREATE TRIGGER trg_collection_items_ad_rollups
+AFTER DELETE ON collection_data_collection_items
+BEGIN
+  UPDATE collection_data_rollups
+  SET total_cards = total_cards - (OLD.quantity_nonfoil + OLD.quantity_foil),
+      total_foils = total_foils - OLD.quantity_foil,
+      row_count = row_count - CASE WHEN OLD.quantity_nonfoil + OLD.quantity_foil > 0 THEN 1 ELSE 0 END
+  WHERE collection_id = OLD.collection_id;
+  UPDATE collection_data_set_rollups
+  SET total_cards = total_cards - (OLD.quantity_nonfoil + OLD.quantity_foil),
+      total_foils = total_foils - OLD.quantity_foil,
+      row_count = row_count - CASE WHEN OLD.quantity_nonfoil + OLD.quantity_foil > 0 THEN 1 ELSE 0 END
+  WHERE collection_id = OLD.collection_id
+    AND set_code = IFNULL((SELECT set_code FROM card_data_printings WHERE id = OLD.printing_id), '');
+  DELETE FROM collection_data_set_rollups
+  WHERE collection_id = OLD.collection_id
+    AND total_cards <= 0
+    AND row_count <= 0;
+  INSERT OR IGNORE INTO collection_data_rollup_dirty (collection_id, printing_id)
+  SELECT OLD.collection_id, OLD.printing_id
+  WHERE EXISTS (SELECT 1 FROM collection_data_collections WHERE id = OLD.collection_id);
+END;
+
+CREATE TRIGGER trg_collection_items_au_rollups
+AFTER UPDATE OF quantity_nonfoil, quantity_foil, printing_id, collection_id ON collection_data_collection_items
+BEGIN
+  UPDATE collection_data_rollups
+  SET total_cards = total_cards - (OLD.quantity_nonfoil + OLD.quantity_foil),
+      total_foils = total_foils - OLD.quantity_foil,
+      row_count = row_count - CASE WHEN OLD.quantity_nonfoil + OLD.quantity_foil > 0 THEN 1 ELSE 0 END
+  WHERE collection_id = OLD.collection_id;
+  UPDATE collection_data_set_rollups
+  SET total_cards = total_cards - (OLD.quantity_nonfoil + OLD.quantity_foil),
+      total_foils = total_foils - OLD.quantity_foil,
+      row_count = row_count - CASE WHEN OLD.quantity_nonfoil + OLD.quantity_foil > 0 THEN 1 ELSE 0 END
+  WHERE collection_id = OLD.collection_id
+    AND set_code = IFNULL((SELECT set_code FROM card_data_printings WHERE id = OLD.printing_id), '');
+  INSERT INTO collection_data_rollups (collection_id, total_cards, total_foils, row_count)
+  VALUES (
+    NEW.collection_id,
+    NEW.quantity_nonfoil + NEW.quantity_foil,
+    NEW.quantity_foil,
+    CASE WHEN NEW.quantity_nonfoil + NEW.quantity_foil > 0 THEN 1 ELSE 0 END
+  )
+  ON CONFLICT(collection_id) DO UPDATE SET
+    total_cards = total_cards + excluded.total_cards,
+    total_foils = total_foils + excluded.total_foils,
+    row_count = row_count + excluded.row_count;
+  INSERT INTO collection_data_set_rollups (collection_id, set_code, total_cards, total_foils, row_count)
+  VALUES (
+    NEW.collection_id,
+    IFNULL((SELECT set_code FROM card_data_printings WHERE id = NEW.printing_id), ''),
+    NEW.quantity_nonfoil + NEW.quantity_foil,
+    NEW.quantity_foil,
+    CASE WHEN NEW.quantity_nonfoil + NEW.quantity_foil > 0 THEN 1 ELSE 0 END
+  )
+  ON CONFLICT(collection_id, set_code) DO UPDATE SET
+    total_cards = total_cards + excluded.total_cards,
+    total_foils = total_foils + excluded.total_foils,
+    row_count = row_count + excluded.row_count;
+  DELETE FROM collection_data_set_rollups
+  WHERE collection_id = OLD.collection_id
+    AND total_cards <= 0
+    AND row_count <= 0;
+  INSERT OR IGNORE INTO collection_data_rollup_dirty (collection_id, printing_id)
+  VALUES (OLD.collection_id, OLD.printing_id);
+  INSERT OR IGNORE INTO collection_data_rollup_dirty (collection_id, printing_id)
+  VALUES (NEW.collection_id, NEW.printing_id);
+END;
+
+-- Backfill from existing rows; value rollups are computed on first read (price_marker -1).
+DELETE FROM collection_data_rollups;
+DELETE FROM collection_data_set_rollups;
+INSERT INTO collection_data_rollups (collection_id, total_cards, total_foils, row_count)
+SELECT
+  ci.collection_id,
+  SUM(ci.quantity_nonfoil + ci.quantity_foil),
+  SUM(ci.quantity_foil),
+  SUM(CASE WHEN ci.quantity_nonfoil + ci.quantity_foil > 0 THEN 1 ELSE 0 END)
+FROM collection_data_collection_items ci
+GROUP BY ci.collection_id;
+INSERT INTO collection_data_set_rollups (collection_id, set_code, total_cards, total_foils, row_count)
+SELECT
+  ci.collection_id,
+  IFNULL(p.set_code, ''),
+  SUM(ci.quantity_nonfoil + ci.quantity_foil),
+  SUM(ci.quantity_foil),
+  SUM(CASE WHEN ci.quantity_nonfoil + ci.quantity_foil > 0 THEN 1 ELSE 0 END)
+FROM collection_data_collection_items ci
+LEFT JOIN card_data_printings p ON p.id = ci.printing_id
+GROUP BY ci.collection_id, IFNULL(p.set_code, '');
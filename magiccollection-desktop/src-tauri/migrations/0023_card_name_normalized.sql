@@ -0,0 +1,8 @@
+PRAGMA foreign_keys = ON;
+
+-- Unaccented, lowercased card name used for name lookups ("Seance" finds "Séance").
+-- Folding happens in the app (fold_card_name); existing rows are backfilled on startup.
+ALTER TABLE card_data_cards ADD COLUMN name_normalized TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_card_data_cards_name_normalized
+  ON card_data_cards(name_normalized);
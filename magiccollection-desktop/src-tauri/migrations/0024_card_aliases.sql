@@ -0,0 +1,41 @@
+PRAGMA foreign_keys = ON;
+
+-- Community nicknames ("Bob", "Goyf") that resolve to a canonical card name.
+-- alias_normalized uses the same folding as card_data_cards.name_normalized.
+CREATE TABLE IF NOT EXISTS card_data_card_aliases (
+  alias_normalized TEXT PRIMARY KEY,
+  alias TEXT NOT NULL,
+  card_name TEXT NOT NULL,
+  is_seeded INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+INSERT OR IGNORE INTO card_data_card_aliases (alias_normalized, alias, card_name, is_seeded, created_at, updated_at)
+VALUES
+  ('ancestral', 'Ancestral', 'Ancestral Recall', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('bob', 'Bob', 'Dark Confidant', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('bolt', 'Bolt', 'Lightning Bolt', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('bop', 'BoP', 'Birds of Paradise', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('coco', 'CoCo', 'Collected Company', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('dockside', 'Dockside', 'Dockside Extortionist', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('drs', 'DRS', 'Deathrite Shaman', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('fow', 'FoW', 'Force of Will', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('goyf', 'Goyf', 'Tarmogoyf', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('jtms', 'JTMS', 'Jace, the Mind Sculptor', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('kiki', 'Kiki', 'Kiki-Jiki, Mirror Breaker', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('lotus', 'Lotus', 'Black Lotus', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('murktide', 'Murktide', 'Murktide Regent', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('oko', 'Oko', 'Oko, Thief of Crowns', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('ragavan', 'Ragavan', 'Ragavan, Nimble Pilferer', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('rhystic', 'Rhystic', 'Rhystic Study', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('scooze', 'Scooze', 'Scavenging Ooze', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('sfm', 'SFM', 'Stoneforge Mystic', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('snapcaster', 'Snapcaster', 'Snapcaster Mage', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('stp', 'StP', 'Swords to Plowshares', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('swords', 'Swords', 'Swords to Plowshares', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('thalia', 'Thalia', 'Thalia, Guardian of Thraben', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('tnn', 'TNN', 'True-Name Nemesis', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('twister', 'Twister', 'Timetwister', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('uro', 'Uro', 'Uro, Titan of Nature''s Wrath', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('w6', 'W6', 'Wrenn and Six', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z');
@@ -0,0 +1,23 @@
+PRAGMA foreign_keys = ON;
+
+-- Optional Commander Spellbook combo dataset, replaced wholesale by sync_combo_dataset.
+-- Pieces are matched against owned cards by oracle id.
+CREATE TABLE IF NOT EXISTS card_data_combos (
+  id TEXT PRIMARY KEY,
+  description TEXT,
+  produces_json TEXT,
+  requires_json TEXT,
+  color_identity TEXT,
+  piece_count INTEGER NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS card_data_combo_pieces (
+  combo_id TEXT NOT NULL REFERENCES card_data_combos(id) ON DELETE CASCADE,
+  oracle_id TEXT NOT NULL,
+  card_name TEXT NOT NULL,
+  PRIMARY KEY (combo_id, oracle_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_card_data_combo_pieces_oracle
+  ON card_data_combo_pieces(oracle_id);
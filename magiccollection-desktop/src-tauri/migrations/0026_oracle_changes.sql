@@ -0,0 +1,17 @@
+PRAGMA foreign_keys = ON;
+
+-- Oracle text changes (errata) seen during metadata syncs for cards someone owns.
+CREATE TABLE IF NOT EXISTS card_data_oracle_changes (
+  id TEXT PRIMARY KEY,
+  card_id TEXT NOT NULL REFERENCES card_data_cards(id) ON DELETE CASCADE,
+  oracle_id TEXT,
+  previous_text TEXT NOT NULL,
+  current_text TEXT NOT NULL,
+  changed_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_card_data_oracle_changes_card
+  ON card_data_oracle_changes(card_id, changed_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_card_data_oracle_changes_changed_at
+  ON card_data_oracle_changes(changed_at DESC);
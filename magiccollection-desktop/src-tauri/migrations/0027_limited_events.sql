@@ -0,0 +1,33 @@
+PRAGMA foreign_keys = ON;
+
+-- Sealed/draft events with their card pool. The pool stays separate from the collection
+-- until merge_limited_pool adds it (merged_at is then set).
+CREATE TABLE IF NOT EXISTS collection_data_limited_events (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  format TEXT NOT NULL,
+  name TEXT,
+  set_code TEXT,
+  event_date TEXT,
+  wins INTEGER NOT NULL DEFAULT 0,
+  losses INTEGER NOT NULL DEFAULT 0,
+  draws INTEGER NOT NULL DEFAULT 0,
+  archetype TEXT,
+  notes TEXT,
+  merged_at TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS collection_data_limited_event_cards (
+  id TEXT PRIMARY KEY,
+  event_id TEXT NOT NULL REFERENCES collection_data_limited_events(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL,
+  UNIQUE(event_id, printing_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_collection_data_limited_events_collection
+  ON collection_data_limited_events(collection_id, event_date DESC);
@@ -0,0 +1,5 @@
+PRAGMA foreign_keys = ON;
+
+-- Per-profile locale for server-side money formatting (`*Formatted` DTO fields, reports,
+-- exports). NULL means the app default (en-US).
+ALTER TABLE collection_data_profiles ADD COLUMN money_locale TEXT;
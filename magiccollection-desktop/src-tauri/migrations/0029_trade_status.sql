@@ -0,0 +1,27 @@
+PRAGMA foreign_keys = ON;
+
+-- Haves/keeps designation per owned row: 'trade' (available to trade or sell), 'keep', or NULL
+-- (undesignated, counted as keep). Rows already tagged for-trade / keep are carried over.
+ALTER TABLE collection_data_collection_items ADD COLUMN trade_status TEXT;
+
+UPDATE collection_data_collection_items
+SET trade_status = 'trade'
+WHERE id IN (
+  SELECT it.collection_item_id
+  FROM collection_data_collection_item_tags it
+  JOIN collection_data_tags t ON t.id = it.tag_id
+  WHERE LOWER(t.name) = 'for-trade'
+);
+
+UPDATE collection_data_collection_items
+SET trade_status = 'keep'
+WHERE trade_status IS NULL
+  AND id IN (
+    SELECT it.collection_item_id
+    FROM collection_data_collection_item_tags it
+    JOIN collection_data_tags t ON t.id = it.tag_id
+    WHERE LOWER(t.name) = 'keep'
+  );
+
+CREATE INDEX IF NOT EXISTS idx_collection_data_items_trade_status
+  ON collection_data_collection_items(collection_id, trade_status);
@@ -0,0 +1,34 @@
+PRAGMA foreign_keys = ON;
+
+-- Physical audits of one location (location_id NULL audits rows without a location). Lines are
+-- keyed like owned rows so scans of cards missing from the checklist land on their own line;
+-- system counts are read live when the discrepancy report is built.
+CREATE TABLE IF NOT EXISTS collection_data_audits (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  location_id TEXT REFERENCES collection_data_locations(id) ON DELETE SET NULL,
+  location_name TEXT,
+  status TEXT NOT NULL DEFAULT 'open',
+  notes TEXT,
+  reconciled_at TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS collection_data_audit_lines (
+  id TEXT PRIMARY KEY,
+  audit_id TEXT NOT NULL REFERENCES collection_data_audits(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  condition_code TEXT NOT NULL,
+  language TEXT NOT NULL,
+  expected_nonfoil INTEGER NOT NULL DEFAULT 0,
+  expected_foil INTEGER NOT NULL DEFAULT 0,
+  counted_nonfoil INTEGER,
+  counted_foil INTEGER,
+  counted_at TEXT,
+  created_at TEXT NOT NULL,
+  UNIQUE(audit_id, printing_id, condition_code, language)
+);
+
+CREATE INDEX IF NOT EXISTS idx_collection_data_audits_collection
+  ON collection_data_audits(collection_id, created_at DESC);
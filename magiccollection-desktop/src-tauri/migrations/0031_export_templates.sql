@@ -0,0 +1,20 @@
+PRAGMA foreign_keys = ON;
+
+-- User-defined export formats rendered with minijinja. CSV templates use columns_json
+-- ([{header, value}], one value template per cell); text and HTML templates use row_template.
+-- header_template and footer_template render once around the rows.
+CREATE TABLE IF NOT EXISTS collection_data_export_templates (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  format TEXT NOT NULL,
+  columns_json TEXT NOT NULL DEFAULT '[]',
+  header_template TEXT,
+  row_template TEXT,
+  footer_template TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_collection_data_export_templates_collection_name
+  ON collection_data_export_templates(collection_id, name);
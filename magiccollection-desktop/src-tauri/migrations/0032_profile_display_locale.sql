@@ -0,0 +1,4 @@
+PRAGMA foreign_keys = ON;
+
+-- Per-profile BCP 47 locale for backend-generated labels and name collation. NULL means en.
+ALTER TABLE collection_data_profiles ADD COLUMN display_locale TEXT;
@@ -0,0 +1,22 @@
+PRAGMA foreign_keys = ON;
+
+-- Internal timings: one row per instrumented command run (metric_kind 'command') and per
+-- statement that crossed the slow-query threshold (metric_kind 'query', SQL text in detail).
+-- app_version lets regressions be compared across releases.
+CREATE TABLE IF NOT EXISTS system_data_performance_metrics (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  metric_kind TEXT NOT NULL,
+  metric_name TEXT NOT NULL,
+  app_version TEXT NOT NULL,
+  duration_ms REAL NOT NULL,
+  rows_processed INTEGER,
+  is_slow INTEGER NOT NULL DEFAULT 0,
+  detail TEXT,
+  recorded_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_system_data_performance_metrics_name
+  ON system_data_performance_metrics(metric_name, recorded_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_system_data_performance_metrics_time
+  ON system_data_performance_metrics(recorded_at);
@@ -0,0 +1,30 @@
+PRAGMA foreign_keys = ON;
+
+-- Decks: named lists of printings split into boards. Deck cards reference catalog printings rather
+-- than owned rows, so a deck can include cards the collection does not have yet. board is main,
+-- side or commander.
+CREATE TABLE IF NOT EXISTS collection_data_decks (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  format TEXT,
+  description TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS collection_data_deck_cards (
+  id TEXT PRIMARY KEY,
+  deck_id TEXT NOT NULL REFERENCES collection_data_decks(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  board TEXT NOT NULL DEFAULT 'main',
+  quantity INTEGER NOT NULL DEFAULT 1,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL,
+  UNIQUE(deck_id, printing_id, board)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_collection_data_decks_collection_name
+  ON collection_data_decks(collection_id, name);
+CREATE INDEX IF NOT EXISTS idx_collection_data_deck_cards_printing
+  ON collection_data_deck_cards(printing_id);
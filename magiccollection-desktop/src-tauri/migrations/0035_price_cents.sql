@@ -0,0 +1,126 @@
+PRAGMA foreign_keys = OFF;
+
+-- Money moves from floating point to integer cents so sums, comparisons and the catalog state
+-- hash are exact. Columns gain a _cents suffix so a query still reading dollars fails loudly.
+-- Sync payloads and command DTOs keep decimal dollars; the backend converts at the SQL boundary.
+BEGIN;
+
+ALTER TABLE card_data_card_prices RENAME TO card_data_card_prices_legacy_0035;
+DROP INDEX IF EXISTS idx_card_data_card_prices_unique_snapshot;
+DROP INDEX IF EXISTS idx_card_data_card_prices_printing_time;
+DROP INDEX IF EXISTS idx_card_data_card_prices_sync_version;
+DROP INDEX IF EXISTS idx_card_data_card_prices_printing_captured_at;
+
+CREATE TABLE card_data_card_prices (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  condition_id INTEGER REFERENCES card_data_condition_codes(id) ON DELETE RESTRICT,
+  finish_id INTEGER REFERENCES card_data_finish_codes(id) ON DELETE RESTRICT,
+  tcg_low_cents INTEGER,
+  tcg_market_cents INTEGER,
+  tcg_high_cents INTEGER,
+  ck_sell_cents INTEGER,
+  ck_buylist_cents INTEGER,
+  ck_buylist_quantity_cap INTEGER,
+  sync_version TEXT NOT NULL,
+  captured_ymd INTEGER,
+  captured_at TEXT NOT NULL,
+  created_at TEXT NOT NULL
+);
+
+CREATE UNIQUE INDEX idx_card_data_card_prices_unique_snapshot
+  ON card_data_card_prices(
+    printing_id,
+    IFNULL(condition_id, 0),
+    IFNULL(finish_id, 0),
+    sync_version
+  );
+
+CREATE INDEX idx_card_data_card_prices_printing_time
+  ON card_data_card_prices(printing_id, captured_ymd DESC);
+
+CREATE INDEX idx_card_data_card_prices_sync_version
+  ON card_data_card_prices(sync_version, captured_ymd DESC);
+
+CREATE INDEX idx_card_data_card_prices_printing_captured_at
+  ON card_data_card_prices(printing_id, captured_at DESC);
+
+-- Ids are kept: value rollups compare MAX(id) to detect price changes.
+INSERT INTO card_data_card_prices (
+  id,
+  printing_id,
+  condition_id,
+  finish_id,
+  tcg_low_cents,
+  tcg_market_cents,
+  tcg_high_cents,
+  ck_sell_cents,
+  ck_buylist_cents,
+  ck_buylist_quantity_cap,
+  sync_version,
+  captured_ymd,
+  captured_at,
+  created_at
+)
+SELECT
+  id,
+  printing_id,
+  condition_id,
+  finish_id,
+  CAST(ROUND(tcg_low * 100) AS INTEGER),
+  CAST(ROUND(tcg_market * 100) AS INTEGER),
+  CAST(ROUND(tcg_high * 100) AS INTEGER),
+  CAST(ROUND(ck_sell * 100) AS INTEGER),
+  CAST(ROUND(ck_buylist * 100) AS INTEGER),
+  ck_buylist_quantity_cap,
+  sync_version,
+  captured_ymd,
+  captured_at,
+  created_at
+FROM card_data_card_prices_legacy_0035;
+
+DROP TABLE card_data_card_prices_legacy_0035;
+
+-- Owned rows, the recycle bin and sales keep their tables (and triggers); only the columns change.
+ALTER TABLE collection_data_collection_items RENAME COLUMN purchase_price TO purchase_price_cents;
+ALTER TABLE collection_data_collection_items RENAME COLUMN asking_price TO asking_price_cents;
+UPDATE collection_data_collection_items
+SET purchase_price_cents = CAST(ROUND(purchase_price_cents * 100) AS INTEGER),
+    asking_price_cents = CAST(ROUND(asking_price_cents * 100) AS INTEGER)
+WHERE purchase_price_cents IS NOT NULL
+   OR asking_price_cents IS NOT NULL;
+
+ALTER TABLE collection_data_removed_items RENAME COLUMN purchase_price TO purchase_price_cents;
+UPDATE collection_data_removed_items
+SET purchase_price_cents = CAST(ROUND(purchase_price_cents * 100) AS INTEGER)
+WHERE purchase_price_cents IS NOT NULL;
+
+ALTER TABLE collection_data_sale_lines RENAME COLUMN unit_price TO unit_price_cents;
+ALTER TABLE collection_data_sale_lines RENAME COLUMN line_total TO line_total_cents;
+UPDATE collection_data_sale_lines
+SET unit_price_cents = CAST(ROUND(unit_price_cents * 100) AS INTEGER),
+    line_total_cents = CAST(ROUND(line_total_cents * 100) AS INTEGER);
+
+ALTER TABLE collection_data_sales RENAME COLUMN total_amount TO total_amount_cents;
+UPDATE collection_data_sales
+SET total_amount_cents = CAST(ROUND(total_amount_cents * 100) AS INTEGER);
+
+-- Value rollups are derived, so the table is recreated empty and refilled on the next totals refresh.
+DROP TABLE collection_data_value_rollups;
+CREATE TABLE collection_data_value_rollups (
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL,
+  channel_id TEXT NOT NULL,
+  total_value_cents INTEGER NOT NULL DEFAULT 0,
+  PRIMARY KEY (collection_id, printing_id, channel_id)
+);
+CREATE INDEX idx_collection_data_value_rollups_channel
+  ON collection_data_value_rollups(collection_id, channel_id);
+UPDATE collection_data_rollups SET price_marker = -1;
+
+-- State accumulators hashed prices as dollar text; they rebuild from the cents rows on demand.
+UPDATE system_data_sync_dataset_versions SET state_accumulator = NULL;
+
+COMMIT;
+
+PRAGMA foreign_keys = ON;
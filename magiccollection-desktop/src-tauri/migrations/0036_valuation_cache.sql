@@ -0,0 +1,116 @@
+PRAGMA foreign_keys = ON;
+
+-- Valuations and insurance reports are cached per collection. A cached row is reused only while
+-- the collection's mutation_counter, the latest price version and the profile's price
+-- preferences all still match what it was computed from.
+ALTER TABLE collection_data_rollups ADD COLUMN mutation_counter INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS collection_data_valuation_cache (
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  cache_key TEXT NOT NULL,
+  mutation_counter INTEGER NOT NULL,
+  price_version TEXT NOT NULL,
+  preferences_key TEXT NOT NULL,
+  payload_json TEXT NOT NULL,
+  computed_at TEXT NOT NULL,
+  PRIMARY KEY (collection_id, cache_key)
+);
+
+-- Every write that can change a valuation bumps the counter: owned rows, tag links, tag names,
+-- decks and deck contents. The rollups row exists before anything is cached, so an UPDATE is enough.
+DROP TRIGGER IF EXISTS trg_collection_items_ai_mutation;
+DROP TRIGGER IF EXISTS trg_collection_items_ad_mutation;
+DROP TRIGGER IF EXISTS trg_collection_items_au_mutation;
+DROP TRIGGER IF EXISTS trg_collection_item_tags_ai_mutation;
+DROP TRIGGER IF EXISTS trg_collection_item_tags_ad_mutation;
+DROP TRIGGER IF EXISTS trg_collection_tags_au_mutation;
+DROP TRIGGER IF EXISTS trg_collection_kits_ai_mutation;
+DROP TRIGGER IF EXISTS trg_collection_kits_ad_mutation;
+DROP TRIGGER IF EXISTS trg_collection_kits_au_mutation;
+DROP TRIGGER IF EXISTS trg_collection_kit_items_ai_mutation;
+DROP TRIGGER IF EXISTS trg_collection_kit_items_ad_mutation;
+DROP TRIGGER IF EXISTS trg_collection_kit_items_au_mutation;
+
+CREATE TRIGGER trg_collection_items_ai_mutation
+AFTER INSERT ON collection_data_collection_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+
+CREATE TRIGGER trg_collection_items_ad_mutation
+AFTER DELETE ON collection_data_collection_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = OLD.collection_id;
+END;
+
+CREATE TRIGGER trg_collection_items_au_mutation
+AFTER UPDATE ON collection_data_collection_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id IN (OLD.collection_id, NEW.collection_id);
+END;
+
+CREATE TRIGGER trg_collection_item_tags_ai_mutation
+AFTER INSERT ON collection_data_collection_item_tags
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_tags WHERE id = NEW.tag_id);
+END;
+
+CREATE TRIGGER trg_collection_item_tags_ad_mutation
+AFTER DELETE ON collection_data_collection_item_tags
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_tags WHERE id = OLD.tag_id);
+END;
+
+CREATE TRIGGER trg_collection_tags_au_mutation
+AFTER UPDATE OF name ON collection_data_tags
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+
+CREATE TRIGGER trg_collection_kits_ai_mutation
+AFTER INSERT ON collection_data_kits
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+
+CREATE TRIGGER trg_collection_kits_ad_mutation
+AFTER DELETE ON collection_data_kits
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = OLD.collection_id;
+END;
+
+CREATE TRIGGER trg_collection_kits_au_mutation
+AFTER UPDATE OF name ON collection_data_kits
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+
+CREATE TRIGGER trg_collection_kit_items_ai_mutation
+AFTER INSERT ON collection_data_kit_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_kits WHERE id = NEW.kit_id);
+END;
+
+CREATE TRIGGER trg_collection_kit_items_ad_mutation
+AFTER DELETE ON collection_data_kit_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_kits WHERE id = OLD.kit_id);
+END;
+
+CREATE TRIGGER trg_collection_kit_items_au_mutation
+AFTER UPDATE ON collection_data_kit_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_kits WHERE id = NEW.kit_id);
+END;
@@ -0,0 +1,26 @@
+PRAGMA foreign_keys = ON;
+
+-- Background work (source syncs, set and combo syncs, metadata hydration) runs through one queue,
+-- one job at a time. `dedupe_key` is the kind plus its payload; a request matching a queued or
+-- running job joins it instead of queueing again.
+CREATE TABLE IF NOT EXISTS system_data_jobs (
+  id TEXT PRIMARY KEY,
+  kind TEXT NOT NULL,
+  dedupe_key TEXT NOT NULL,
+  payload_json TEXT,
+  status TEXT NOT NULL DEFAULT 'queued',
+  phase TEXT NOT NULL DEFAULT 'queued',
+  progress_json TEXT,
+  result_json TEXT,
+  error TEXT,
+  cancel_requested INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL,
+  started_at TEXT,
+  finished_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_system_data_jobs_status_created
+  ON system_data_jobs(status, created_at);
+
+CREATE INDEX IF NOT EXISTS idx_system_data_jobs_dedupe
+  ON system_data_jobs(dedupe_key, status);
@@ -0,0 +1,26 @@
+PRAGMA foreign_keys = ON;
+
+-- Purchase prices carry the currency they were paid in. Profiles get a default for new entries;
+-- rows priced before this migration were entered as dollars, so they take USD.
+ALTER TABLE collection_data_profiles ADD COLUMN default_purchase_currency TEXT;
+ALTER TABLE collection_data_collection_items ADD COLUMN purchase_currency TEXT;
+ALTER TABLE collection_data_removed_items ADD COLUMN purchase_currency TEXT;
+
+UPDATE collection_data_collection_items
+SET purchase_currency = 'USD'
+WHERE purchase_price_cents IS NOT NULL;
+
+UPDATE collection_data_removed_items
+SET purchase_currency = 'USD'
+WHERE purchase_price_cents IS NOT NULL;
+
+-- Daily reference rates (ECB, via Frankfurter) for converting purchase prices to the valuation
+-- currency as of the purchase date. `units_per_usd` is how much of `currency` one dollar bought.
+CREATE TABLE IF NOT EXISTS card_data_fx_rates (
+  currency TEXT NOT NULL,
+  rate_date TEXT NOT NULL,
+  units_per_usd REAL NOT NULL,
+  source TEXT NOT NULL,
+  captured_at TEXT NOT NULL,
+  PRIMARY KEY (currency, rate_date)
+);
@@ -0,0 +1,5 @@
+PRAGMA foreign_keys = ON;
+
+-- Profiles can keep owned rows whose last copy is gone as "previously owned" records, so notes,
+-- tags and purchase history survive selling and rebuying. Off keeps the old delete-at-zero behavior.
+ALTER TABLE collection_data_profiles ADD COLUMN retain_zero_quantity_rows INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,9 @@
+PRAGMA foreign_keys = ON;
+
+-- Server-side collection paging sorts owned rows by copy count and reads the latest prices of
+-- each condition + finish series; these indexes back those ORDER BY and window scans.
+CREATE INDEX IF NOT EXISTS idx_collection_data_items_collection_copies
+  ON collection_data_collection_items(collection_id, (quantity_nonfoil + quantity_foil));
+
+CREATE INDEX IF NOT EXISTS idx_card_data_card_prices_series_time
+  ON card_data_card_prices(printing_id, condition_id, finish_id, captured_at DESC);
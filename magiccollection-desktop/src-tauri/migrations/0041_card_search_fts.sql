@@ -0,0 +1,127 @@
+PRAGMA foreign_keys = ON;
+
+-- Full-text indexes behind search_cards. Both FTS tables are contentless (the text already lives
+-- in card_data_cards and the item notes), so each gets a key table mapping its stable integer
+-- rowid to the TEXT id of the source row; implicit rowids are not kept stable across VACUUM.
+CREATE TABLE IF NOT EXISTS card_data_card_search_keys (
+  search_rowid INTEGER PRIMARY KEY,
+  card_id TEXT NOT NULL UNIQUE
+);
+
+CREATE VIRTUAL TABLE IF NOT EXISTS card_data_cards_fts USING fts5(
+  name,
+  type_line,
+  oracle_text,
+  content = '',
+  contentless_delete = 1,
+  tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TABLE IF NOT EXISTS collection_data_item_note_search_keys (
+  search_rowid INTEGER PRIMARY KEY,
+  collection_item_id TEXT NOT NULL UNIQUE
+);
+
+CREATE VIRTUAL TABLE IF NOT EXISTS collection_data_item_notes_fts USING fts5(
+  notes,
+  content = '',
+  contentless_delete = 1,
+  tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT OR IGNORE INTO card_data_card_search_keys (card_id)
+SELECT id FROM card_data_cards;
+
+INSERT INTO card_data_cards_fts (rowid, name, type_line, oracle_text)
+SELECT k.search_rowid, c.name, c.type_line, c.oracle_text
+FROM card_data_card_search_keys k
+JOIN card_data_cards c ON c.id = k.card_id;
+
+INSERT OR IGNORE INTO collection_data_item_note_search_keys (collection_item_id)
+SELECT id FROM collection_data_collection_items
+WHERE trim(COALESCE(notes, '')) <> '';
+
+INSERT INTO collection_data_item_notes_fts (rowid, notes)
+SELECT k.search_rowid, ci.notes
+FROM collection_data_item_note_search_keys k
+JOIN collection_data_collection_items ci ON ci.id = k.collection_item_id;
+
+-- Catalog imports upsert every card, so the UPDATE triggers only re-index text that changed.
+DROP TRIGGER IF EXISTS trg_card_data_cards_ai_search;
+DROP TRIGGER IF EXISTS trg_card_data_cards_ad_search;
+DROP TRIGGER IF EXISTS trg_card_data_cards_au_search;
+DROP TRIGGER IF EXISTS trg_collection_items_ai_search;
+DROP TRIGGER IF EXISTS trg_collection_items_ad_search;
+DROP TRIGGER IF EXISTS trg_collection_items_au_search;
+
+CREATE TRIGGER trg_card_data_cards_ai_search
+AFTER INSERT ON card_data_cards
+BEGIN
+  INSERT OR IGNORE INTO card_data_card_search_keys (card_id) VALUES (NEW.id);
+  INSERT INTO card_data_cards_fts (rowid, name, type_line, oracle_text)
+  VALUES (
+    (SELECT search_rowid FROM card_data_card_search_keys WHERE card_id = NEW.id),
+    NEW.name,
+    NEW.type_line,
+    NEW.oracle_text
+  );
+END;
+
+CREATE TRIGGER trg_card_data_cards_ad_search
+AFTER DELETE ON card_data_cards
+BEGIN
+  DELETE FROM card_data_cards_fts
+  WHERE rowid = (SELECT search_rowid FROM card_data_card_search_keys WHERE card_id = OLD.id);
+  DELETE FROM card_data_card_search_keys WHERE card_id = OLD.id;
+END;
+
+CREATE TRIGGER trg_card_data_cards_au_search
+AFTER UPDATE OF name, type_line, oracle_text ON card_data_cards
+WHEN OLD.name IS NOT NEW.name
+  OR OLD.type_line IS NOT NEW.type_line
+  OR OLD.oracle_text IS NOT NEW.oracle_text
+BEGIN
+  UPDATE card_data_cards_fts
+  SET name = NEW.name, type_line = NEW.type_line, oracle_text = NEW.oracle_text
+  WHERE rowid = (SELECT search_rowid FROM card_data_card_search_keys WHERE card_id = NEW.id);
+END;
+
+CREATE TRIGGER trg_collection_items_ai_search
+AFTER INSERT ON collection_data_collection_items
+WHEN trim(COALESCE(NEW.notes, '')) <> ''
+BEGIN
+  INSERT OR IGNORE INTO collection_data_item_note_search_keys (collection_item_id) VALUES (NEW.id);
+  INSERT INTO collection_data_item_notes_fts (rowid, notes)
+  VALUES (
+    (SELECT search_rowid FROM collection_data_item_note_search_keys WHERE collection_item_id = NEW.id),
+    NEW.notes
+  );
+END;
+
+CREATE TRIGGER trg_collection_items_ad_search
+AFTER DELETE ON collection_data_collection_items
+BEGIN
+  DELETE FROM collection_data_item_notes_fts
+  WHERE rowid = (
+    SELECT search_rowid FROM collection_data_item_note_search_keys WHERE collection_item_id = OLD.id
+  );
+  DELETE FROM collection_data_item_note_search_keys WHERE collection_item_id = OLD.id;
+END;
+
+-- Notes are re-indexed from scratch on change so rows that gain or lose their notes stay in step.
+CREATE TRIGGER trg_collection_items_au_search
+AFTER UPDATE OF notes ON collection_data_collection_items
+WHEN OLD.notes IS NOT NEW.notes
+BEGIN
+  DELETE FROM collection_data_item_notes_fts
+  WHERE rowid = (
+    SELECT search_rowid FROM collection_data_item_note_search_keys WHERE collection_item_id = OLD.id
+  );
+  DELETE FROM collection_data_item_note_search_keys WHERE collection_item_id = OLD.id;
+  INSERT INTO collection_data_item_note_search_keys (collection_item_id)
+  SELECT NEW.id WHERE trim(COALESCE(NEW.notes, '')) <> '';
+  INSERT INTO collection_data_item_notes_fts (rowid, notes)
+  SELECT search_rowid, NEW.notes
+  FROM collection_data_item_note_search_keys
+  WHERE collection_item_id = NEW.id;
+END;
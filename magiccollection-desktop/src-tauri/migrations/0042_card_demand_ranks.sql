@@ -0,0 +1,12 @@
+PRAGMA foreign_keys = ON;
+
+-- Aggregate play-rate ranks per card name, as published in Scryfall card data: `edhrec` is the
+-- EDHREC popularity rank across Commander decks, `penny` the Penny Dreadful rank. Lower is more
+-- played. Ranks are per oracle card, so rows key on the folded name shared by every printing.
+CREATE TABLE IF NOT EXISTS card_data_demand_ranks (
+  name_normalized TEXT NOT NULL,
+  source TEXT NOT NULL,
+  rank INTEGER NOT NULL,
+  updated_at TEXT NOT NULL,
+  PRIMARY KEY (name_normalized, source)
+);
@@ -0,0 +1,16 @@
+PRAGMA foreign_keys = ON;
+
+-- Daily collection value history. Values are USD cents, like the value rollups they are read
+-- from; purchase cost converts each purchase at its purchase-date exchange rate. Recording again
+-- on the same day replaces that day's row.
+CREATE TABLE IF NOT EXISTS collection_data_value_snapshots (
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  snapshot_date TEXT NOT NULL,
+  market_value_cents INTEGER NOT NULL,
+  low_value_cents INTEGER NOT NULL,
+  buylist_value_cents INTEGER NOT NULL,
+  purchase_cost_cents INTEGER NOT NULL,
+  total_cards INTEGER NOT NULL,
+  captured_at TEXT NOT NULL,
+  PRIMARY KEY (collection_id, snapshot_date)
+);
@@ -0,0 +1,15 @@
+PRAGMA foreign_keys = ON;
+
+-- Cost basis of each sold copy in USD cents, converted at the purchase-date rate when the sale is
+-- recorded, so realized gains survive the owned row being cleared. NULL when the copy had no
+-- purchase price or no rate. Older dollar-priced lines whose row still exists are backfilled.
+ALTER TABLE collection_data_sale_lines ADD COLUMN unit_cost_cents NUMERIC;
+
+UPDATE collection_data_sale_lines
+SET unit_cost_cents = (
+  SELECT ci.purchase_price_cents
+  FROM collection_data_collection_items ci
+  WHERE ci.id = collection_data_sale_lines.collection_item_id
+    AND IFNULL(ci.purchase_currency, 'USD') = 'USD'
+)
+WHERE collection_item_id IS NOT NULL;
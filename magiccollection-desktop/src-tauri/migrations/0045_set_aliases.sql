@@ -0,0 +1,44 @@
+PRAGMA foreign_keys = ON;
+
+-- Set names and codes that scanning services and older exports (Card Castle, PucaTrade, Deckbox)
+-- print differently from Scryfall, mapped to the Scryfall set code. alias_normalized uses the
+-- same folding as card_data_card_aliases. Shipped rows are seeded; users add and edit their own.
+CREATE TABLE IF NOT EXISTS card_data_set_aliases (
+  alias_normalized TEXT PRIMARY KEY,
+  alias TEXT NOT NULL,
+  set_code TEXT NOT NULL,
+  is_seeded INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+INSERT OR IGNORE INTO card_data_set_aliases (alias_normalized, alias, set_code, is_seeded, created_at, updated_at)
+VALUES
+  ('10th edition', '10th Edition', '10e', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('4th edition', '4th Edition', '4ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('5th edition', '5th Edition', '5ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('6th edition', '6th Edition', '6ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('7th edition', '7th Edition', '7ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('8th edition', '8th Edition', '8ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('9th edition', '9th Edition', '9ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('alpha', 'Alpha', 'lea', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('beta', 'Beta', 'leb', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('classic sixth edition', 'Classic Sixth Edition', '6ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('commander', 'Commander', 'cmd', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('commander 2013 edition', 'Commander 2013 Edition', 'c13', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('commander 2014 edition', 'Commander 2014 Edition', 'c14', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('magic 2019', 'Magic 2019', 'm19', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('magic 2020', 'Magic 2020', 'm20', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('magic: the gathering-commander', 'Magic: The Gathering-Commander', 'cmd', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('magic: the gathering—conspiracy', 'Magic: The Gathering—Conspiracy', 'cns', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('masterpiece series: amonkhet invocations', 'Masterpiece Series: Amonkhet Invocations', 'mp2', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('masterpiece series: kaladesh inventions', 'Masterpiece Series: Kaladesh Inventions', 'mps', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('modern masters 2015 edition', 'Modern Masters 2015 Edition', 'mm2', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('modern masters 2017 edition', 'Modern Masters 2017 Edition', 'mm3', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('planechase 2012 edition', 'Planechase 2012 Edition', 'pc2', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('ravnica', 'Ravnica', 'rav', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('revised', 'Revised', '3ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('time spiral "timeshifted"', 'Time Spiral "Timeshifted"', 'tsb', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('timeshifted', 'Timeshifted', 'tsb', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('ultimate box toppers', 'Ultimate Box Toppers', 'puma', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('unlimited', 'Unlimited', '2ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z');
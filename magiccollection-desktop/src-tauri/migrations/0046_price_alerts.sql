@@ -0,0 +1,33 @@
+PRAGMA foreign_keys = ON;
+
+-- Price thresholds on a printing's price channel. An alert fires once when the latest price
+-- reaches its threshold and re-arms after the price moves back; `triggered` tracks that state.
+CREATE TABLE IF NOT EXISTS collection_data_price_alerts (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  source_id TEXT NOT NULL,
+  direction TEXT NOT NULL,
+  threshold_cents INTEGER NOT NULL,
+  is_foil INTEGER NOT NULL DEFAULT 0,
+  triggered INTEGER NOT NULL DEFAULT 0,
+  last_fired_at TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_collection_data_price_alerts_collection
+  ON collection_data_price_alerts(collection_id);
+
+-- One row per firing, with the price that crossed the threshold.
+CREATE TABLE IF NOT EXISTS collection_data_price_alert_events (
+  id TEXT PRIMARY KEY,
+  alert_id TEXT NOT NULL REFERENCES collection_data_price_alerts(id) ON DELETE CASCADE,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  price_cents INTEGER NOT NULL,
+  threshold_cents INTEGER NOT NULL,
+  fired_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_collection_data_price_alert_events_collection
+  ON collection_data_price_alert_events(collection_id, fired_at);
@@ -0,0 +1,27 @@
+PRAGMA foreign_keys = ON;
+
+-- More shipped set aliases: The List as exporters spell it, Mystery Booster and Secret Lair names,
+-- and core set names as German, French, Spanish and Italian lists print them. Promo sets written
+-- as "<set> Promos" or "Promo: <set>" resolve in code, not here.
+INSERT OR IGNORE INTO card_data_set_aliases (alias_normalized, alias, set_code, is_seeded, created_at, updated_at)
+VALUES
+  ('base set 2019', 'Base Set 2019', 'm19', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('base set 2020', 'Base Set 2020', 'm20', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('coleccion basica 2019', 'Colección básica 2019', 'm19', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('coleccion basica 2020', 'Colección básica 2020', 'm20', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('edition de base 2019', 'Édition de base 2019', 'm19', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('edition de base 2020', 'Édition de base 2020', 'm20', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('hauptset 2020', 'Hauptset 2020', 'm20', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('kernset 2019', 'Kernset 2019', 'm19', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('list', 'List', 'plst', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('mb1 list', 'MB1 List', 'plst', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('mystery booster', 'Mystery Booster', 'mb1', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('mystery booster playtest cards', 'Mystery Booster Playtest Cards', 'cmb1', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('plist', 'PLIST', 'plst', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('secret lair', 'Secret Lair', 'sld', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('secret lair drop', 'Secret Lair Drop', 'sld', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('set base 2019', 'Set Base 2019', 'm19', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('set base 2020', 'Set Base 2020', 'm20', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('the list', 'The List', 'plst', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('the list (unfinity foil edition)', 'The List (Unfinity Foil Edition)', 'ulst', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('unfinity list', 'Unfinity List', 'ulst', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z');
@@ -0,0 +1,43 @@
+PRAGMA foreign_keys = ON;
+
+-- Arena and MTGO holdings, one row per profile, platform and printing. Kept out of
+-- collection_data_collection_items so digital copies never count toward the paper collection.
+-- Each import replaces the platform's rows, as client and tracker exports are full snapshots.
+CREATE TABLE IF NOT EXISTS collection_data_digital_items (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  platform TEXT NOT NULL,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
+  imported_at TEXT NOT NULL,
+  UNIQUE (collection_id, platform, printing_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_collection_data_digital_items_printing
+  ON collection_data_digital_items(printing_id);
+
+-- Set codes the MTGO client and Arena print differently from Scryfall.
+INSERT OR IGNORE INTO card_data_set_aliases (alias_normalized, alias, set_code, is_seeded, created_at, updated_at)
+VALUES
+  ('4e', '4E', '4ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('5e', '5E', '5ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('6e', '6E', '6ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('7e', '7E', '7ed', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('ap', 'AP', 'apc', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('cg', 'CG', 'uds', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('dar', 'DAR', 'dom', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('ex', 'EX', 'exo', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('gu', 'GU', 'ulg', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('in', 'IN', 'inv', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('mi', 'MI', 'mir', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('mm', 'MM', 'mmq', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('ne', 'NE', 'nem', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('od', 'OD', 'ody', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('pr', 'PR', 'pcy', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('ps', 'PS', 'pls', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('st', 'ST', 'sth', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('te', 'TE', 'tmp', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('uz', 'UZ', 'usg', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('vi', 'VI', 'vis', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+  ('wl', 'WL', 'wth', 1, '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z');
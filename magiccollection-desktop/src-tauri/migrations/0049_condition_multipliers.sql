@@ -0,0 +1,5 @@
+PRAGMA foreign_keys = ON;
+
+-- Per-profile share of the NM price each condition is worth in condition-adjusted valuations,
+-- e.g. {"LP": 0.85, "HP": 0.5}. NULL uses the built-in table.
+ALTER TABLE collection_data_profiles ADD COLUMN condition_multipliers_json TEXT;
@@ -0,0 +1,18 @@
+PRAGMA foreign_keys = ON;
+
+-- Card images waiting for (or already in) the on-disk image cache, one row per URL so a printing
+-- queued twice downloads once. Rows outlive the job that drains them, so an interrupted prefetch
+-- resumes where it stopped.
+CREATE TABLE IF NOT EXISTS system_data_image_prefetch_queue (
+  url TEXT PRIMARY KEY,
+  status TEXT NOT NULL DEFAULT 'queued',
+  attempts INTEGER NOT NULL DEFAULT 0,
+  file_name TEXT,
+  bytes INTEGER,
+  last_error TEXT,
+  queued_at TEXT NOT NULL,
+  fetched_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_system_data_image_prefetch_queue_status
+  ON system_data_image_prefetch_queue(status, queued_at);
@@ -0,0 +1,5 @@
+PRAGMA foreign_keys = ON;
+
+-- Per-profile price multipliers for non-English copies relative to the English price, by finish,
+-- e.g. {"nonfoil": {"ja": 1.1}, "foil": {"ja": 1.5}}. NULL uses the built-in table.
+ALTER TABLE collection_data_profiles ADD COLUMN language_premiums_json TEXT;
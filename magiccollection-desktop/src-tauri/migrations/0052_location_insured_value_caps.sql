@@ -0,0 +1,14 @@
+PRAGMA foreign_keys = ON;
+
+-- Insured value cap per location in the profile's valuation currency, in cents. NULL is uncapped.
+ALTER TABLE collection_data_locations ADD COLUMN insured_value_cap_cents NUMERIC;
+
+-- The insurance report names locations and checks their caps, so both invalidate cached valuations.
+DROP TRIGGER IF EXISTS trg_collection_locations_au_mutation;
+
+CREATE TRIGGER trg_collection_locations_au_mutation
+AFTER UPDATE OF name, insured_value_cap_cents ON collection_data_locations
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
@@ -0,0 +1,27 @@
+PRAGMA foreign_keys = ON;
+
+-- Language premiums are keyed by collection language codes, where Japanese is "jp". Overrides
+-- saved under Scryfall's "ja" move to "jp"; an existing "jp" entry wins.
+UPDATE collection_data_profiles
+SET language_premiums_json = json_set(
+  language_premiums_json,
+  '$.nonfoil.jp',
+  json_extract(language_premiums_json, '$.nonfoil.ja')
+)
+WHERE json_valid(language_premiums_json)
+  AND json_type(language_premiums_json, '$.nonfoil.ja') IS NOT NULL
+  AND json_type(language_premiums_json, '$.nonfoil.jp') IS NULL;
+
+UPDATE collection_data_profiles
+SET language_premiums_json = json_set(
+  language_premiums_json,
+  '$.foil.jp',
+  json_extract(language_premiums_json, '$.foil.ja')
+)
+WHERE json_valid(language_premiums_json)
+  AND json_type(language_premiums_json, '$.foil.ja') IS NOT NULL
+  AND json_type(language_premiums_json, '$.foil.jp') IS NULL;
+
+UPDATE collection_data_profiles
+SET language_premiums_json = json_remove(language_premiums_json, '$.nonfoil.ja', '$.foil.ja')
+WHERE json_valid(language_premiums_json);
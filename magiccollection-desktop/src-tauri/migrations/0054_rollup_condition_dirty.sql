@@ -0,0 +1,14 @@
+PRAGMA foreign_keys = ON;
+
+-- Value rollups price copies at their own condition, so a condition change marks the printing dirty.
+DROP TRIGGER IF EXISTS trg_collection_items_au_rollup_condition;
+
+CREATE TRIGGER trg_collection_items_au_rollup_condition
+AFTER UPDATE OF condition_code ON collection_data_collection_items
+BEGIN
+  INSERT OR IGNORE INTO collection_data_rollup_dirty (collection_id, printing_id)
+  VALUES (NEW.collection_id, NEW.printing_id);
+END;
+
+-- Rollups were priced at NM; force a full recompute.
+UPDATE collection_data_rollups SET price_marker = -1;
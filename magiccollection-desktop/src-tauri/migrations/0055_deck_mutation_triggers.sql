@@ -0,0 +1,51 @@
+PRAGMA foreign_keys = ON;
+
+-- Valuations group by deck, so deck and deck card changes invalidate cached valuations.
+DROP TRIGGER IF EXISTS trg_collection_decks_ai_mutation;
+DROP TRIGGER IF EXISTS trg_collection_decks_ad_mutation;
+DROP TRIGGER IF EXISTS trg_collection_decks_au_mutation;
+DROP TRIGGER IF EXISTS trg_collection_deck_cards_ai_mutation;
+DROP TRIGGER IF EXISTS trg_collection_deck_cards_ad_mutation;
+DROP TRIGGER IF EXISTS trg_collection_deck_cards_au_mutation;
+
+CREATE TRIGGER trg_collection_decks_ai_mutation
+AFTER INSERT ON collection_data_decks
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+
+CREATE TRIGGER trg_collection_decks_ad_mutation
+AFTER DELETE ON collection_data_decks
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = OLD.collection_id;
+END;
+
+CREATE TRIGGER trg_collection_decks_au_mutation
+AFTER UPDATE OF name ON collection_data_decks
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+
+CREATE TRIGGER trg_collection_deck_cards_ai_mutation
+AFTER INSERT ON collection_data_deck_cards
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_decks WHERE id = NEW.deck_id);
+END;
+
+CREATE TRIGGER trg_collection_deck_cards_ad_mutation
+AFTER DELETE ON collection_data_deck_cards
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_decks WHERE id = OLD.deck_id);
+END;
+
+CREATE TRIGGER trg_collection_deck_cards_au_mutation
+AFTER UPDATE ON collection_data_deck_cards
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_decks WHERE id = NEW.deck_id);
+END;
@@ -0,0 +1,9 @@
+PRAGMA foreign_keys = ON;
+
+-- Recycle bin entries keep everything a restore needs: the retail fields, attachment rows (their
+-- files stay on disk until the entry expires) and reservation history, all of which used to be
+-- lost with the deleted row.
+ALTER TABLE collection_data_removed_items ADD COLUMN asking_price_cents NUMERIC;
+ALTER TABLE collection_data_removed_items ADD COLUMN trade_status TEXT;
+ALTER TABLE collection_data_removed_items ADD COLUMN attachments_json TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE collection_data_removed_items ADD COLUMN reservations_json TEXT NOT NULL DEFAULT '[]';
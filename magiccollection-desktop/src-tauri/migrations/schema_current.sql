@@ -1,6 +1,40 @@
 -- Canonical current schema for fresh installs.
--- Generated from migrations 0004-0009.
+-- Generated from migrations 0004-0056.
 PRAGMA foreign_keys = OFF;
+CREATE TABLE card_data_card_aliases (
+  alias_normalized TEXT PRIMARY KEY,
+  alias TEXT NOT NULL,
+  card_name TEXT NOT NULL,
+  is_seeded INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+INSERT INTO "card_data_card_aliases" VALUES('ancestral','Ancestral','Ancestral Recall',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('bob','Bob','Dark Confidant',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('bolt','Bolt','Lightning Bolt',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('bop','BoP','Birds of Paradise',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('coco','CoCo','Collected Company',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('dockside','Dockside','Dockside Extortionist',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('drs','DRS','Deathrite Shaman',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('fow','FoW','Force of Will',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('goyf','Goyf','Tarmogoyf',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('jtms','JTMS','Jace, the Mind Sculptor',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('kiki','Kiki','Kiki-Jiki, Mirror Breaker',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('lotus','Lotus','Black Lotus',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('murktide','Murktide','Murktide Regent',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('oko','Oko','Oko, Thief of Crowns',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('ragavan','Ragavan','Ragavan, Nimble Pilferer',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('rhystic','Rhystic','Rhystic Study',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('scooze','Scooze','Scavenging Ooze',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('sfm','SFM','Stoneforge Mystic',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('snapcaster','Snapcaster','Snapcaster Mage',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('stp','StP','Swords to Plowshares',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('swords','Swords','Swords to Plowshares',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('thalia','Thalia','Thalia, Guardian of Thraben',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('tnn','TNN','True-Name Nemesis',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('twister','Twister','Timetwister',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('uro','Uro','Uro, Titan of Nature''s Wrath',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_card_aliases" VALUES('w6','W6','Wrenn and Six',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
 CREATE TABLE card_data_card_faces (
   id TEXT PRIMARY KEY,
   printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
@@ -24,17 +58,21 @@ CREATE TABLE card_data_card_prices (
   printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
   condition_id INTEGER REFERENCES card_data_condition_codes(id) ON DELETE RESTRICT,
   finish_id INTEGER REFERENCES card_data_finish_codes(id) ON DELETE RESTRICT,
-  tcg_low NUMERIC,
-  tcg_market NUMERIC,
-  tcg_high NUMERIC,
-  ck_sell NUMERIC,
-  ck_buylist NUMERIC,
+  tcg_low_cents INTEGER,
+  tcg_market_cents INTEGER,
+  tcg_high_cents INTEGER,
+  ck_sell_cents INTEGER,
+  ck_buylist_cents INTEGER,
   ck_buylist_quantity_cap INTEGER,
   sync_version TEXT NOT NULL,
   captured_ymd INTEGER,
   captured_at TEXT NOT NULL,
   created_at TEXT NOT NULL
 );
+CREATE TABLE card_data_card_search_keys (
+  search_rowid INTEGER PRIMARY KEY,
+  card_id TEXT NOT NULL UNIQUE
+);
 CREATE TABLE card_data_cards (
   id TEXT PRIMARY KEY,
   oracle_id TEXT UNIQUE,
@@ -49,6 +87,30 @@ CREATE TABLE card_data_cards (
   color_identity_json TEXT,
   latest_released_at TEXT,
   created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL,
+  name_normalized TEXT
+);
+CREATE VIRTUAL TABLE card_data_cards_fts USING fts5(
+  name,
+  type_line,
+  oracle_text,
+  content = '',
+  contentless_delete = 1,
+  tokenize = 'unicode61 remove_diacritics 2'
+);
+CREATE TABLE card_data_combo_pieces (
+  combo_id TEXT NOT NULL REFERENCES card_data_combos(id) ON DELETE CASCADE,
+  oracle_id TEXT NOT NULL,
+  card_name TEXT NOT NULL,
+  PRIMARY KEY (combo_id, oracle_id)
+);
+CREATE TABLE card_data_combos (
+  id TEXT PRIMARY KEY,
+  description TEXT,
+  produces_json TEXT,
+  requires_json TEXT,
+  color_identity TEXT,
+  piece_count INTEGER NOT NULL,
   updated_at TEXT NOT NULL
 );
 CREATE TABLE card_data_condition_codes (
@@ -74,6 +136,13 @@ INSERT INTO "card_data_currency_codes" VALUES(1,'USD','$');
 INSERT INTO "card_data_currency_codes" VALUES(2,'EUR','EUR');
 INSERT INTO "card_data_currency_codes" VALUES(3,'TIX','TIX');
 INSERT INTO "card_data_currency_codes" VALUES(99,'UNK',NULL);
+CREATE TABLE card_data_demand_ranks (
+  name_normalized TEXT NOT NULL,
+  source TEXT NOT NULL,
+  rank INTEGER NOT NULL,
+  updated_at TEXT NOT NULL,
+  PRIMARY KEY (name_normalized, source)
+);
 CREATE TABLE card_data_finish_codes (
   id INTEGER PRIMARY KEY,
   finish_code TEXT NOT NULL UNIQUE,
@@ -83,6 +152,14 @@ INSERT INTO "card_data_finish_codes" VALUES(1,'N',1);
 INSERT INTO "card_data_finish_codes" VALUES(2,'F',2);
 INSERT INTO "card_data_finish_codes" VALUES(3,'E',3);
 INSERT INTO "card_data_finish_codes" VALUES(99,'U',99);
+CREATE TABLE card_data_fx_rates (
+  currency TEXT NOT NULL,
+  rate_date TEXT NOT NULL,
+  units_per_usd REAL NOT NULL,
+  source TEXT NOT NULL,
+  captured_at TEXT NOT NULL,
+  PRIMARY KEY (currency, rate_date)
+);
 CREATE TABLE card_data_legalities (
   printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
   format_code TEXT NOT NULL,
@@ -91,6 +168,14 @@ CREATE TABLE card_data_legalities (
   updated_at TEXT NOT NULL,
   PRIMARY KEY (printing_id, format_code)
 );
+CREATE TABLE card_data_oracle_changes (
+  id TEXT PRIMARY KEY,
+  card_id TEXT NOT NULL REFERENCES card_data_cards(id) ON DELETE CASCADE,
+  oracle_id TEXT,
+  previous_text TEXT NOT NULL,
+  current_text TEXT NOT NULL,
+  changed_at TEXT NOT NULL
+);
 CREATE TABLE card_data_otags (
   id TEXT PRIMARY KEY,
   source TEXT NOT NULL,
@@ -177,6 +262,83 @@ CREATE TABLE card_data_printings (
   created_at TEXT NOT NULL,
   updated_at TEXT NOT NULL
 );
+CREATE TABLE card_data_set_aliases (
+  alias_normalized TEXT PRIMARY KEY,
+  alias TEXT NOT NULL,
+  set_code TEXT NOT NULL,
+  is_seeded INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+INSERT INTO "card_data_set_aliases" VALUES('10th edition','10th Edition','10e',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('4e','4E','4ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('4th edition','4th Edition','4ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('5e','5E','5ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('5th edition','5th Edition','5ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('6e','6E','6ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('6th edition','6th Edition','6ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('7e','7E','7ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('7th edition','7th Edition','7ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('8th edition','8th Edition','8ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('9th edition','9th Edition','9ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('alpha','Alpha','lea',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('ap','AP','apc',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('base set 2019','Base Set 2019','m19',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('base set 2020','Base Set 2020','m20',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('beta','Beta','leb',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('cg','CG','uds',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('classic sixth edition','Classic Sixth Edition','6ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('coleccion basica 2019','Colección básica 2019','m19',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('coleccion basica 2020','Colección básica 2020','m20',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('commander','Commander','cmd',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('commander 2013 edition','Commander 2013 Edition','c13',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('commander 2014 edition','Commander 2014 Edition','c14',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('dar','DAR','dom',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('edition de base 2019','Édition de base 2019','m19',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('edition de base 2020','Édition de base 2020','m20',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('ex','EX','exo',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('gu','GU','ulg',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('hauptset 2020','Hauptset 2020','m20',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('in','IN','inv',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('kernset 2019','Kernset 2019','m19',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('list','List','plst',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('magic 2019','Magic 2019','m19',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('magic 2020','Magic 2020','m20',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('magic: the gathering-commander','Magic: The Gathering-Commander','cmd',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('magic: the gathering—conspiracy','Magic: The Gathering—Conspiracy','cns',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('masterpiece series: amonkhet invocations','Masterpiece Series: Amonkhet Invocations','mp2',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('masterpiece series: kaladesh inventions','Masterpiece Series: Kaladesh Inventions','mps',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('mb1 list','MB1 List','plst',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('mi','MI','mir',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('mm','MM','mmq',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('modern masters 2015 edition','Modern Masters 2015 Edition','mm2',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('modern masters 2017 edition','Modern Masters 2017 Edition','mm3',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('mystery booster','Mystery Booster','mb1',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('mystery booster playtest cards','Mystery Booster Playtest Cards','cmb1',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('ne','NE','nem',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('od','OD','ody',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('planechase 2012 edition','Planechase 2012 Edition','pc2',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('plist','PLIST','plst',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('pr','PR','pcy',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('ps','PS','pls',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('ravnica','Ravnica','rav',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('revised','Revised','3ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('secret lair','Secret Lair','sld',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('secret lair drop','Secret Lair Drop','sld',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('set base 2019','Set Base 2019','m19',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('set base 2020','Set Base 2020','m20',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('st','ST','sth',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('te','TE','tmp',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('the list','The List','plst',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('the list (unfinity foil edition)','The List (Unfinity Foil Edition)','ulst',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('time spiral "timeshifted"','Time Spiral "Timeshifted"','tsb',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('timeshifted','Timeshifted','tsb',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('ultimate box toppers','Ultimate Box Toppers','puma',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('unfinity list','Unfinity List','ulst',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('unlimited','Unlimited','2ed',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('uz','UZ','usg',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('vi','VI','vis',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
+INSERT INTO "card_data_set_aliases" VALUES('wl','WL','wth',1,'2026-10-18T00:00:00Z','2026-10-18T00:00:00Z');
 CREATE TABLE card_data_sets (
   set_code TEXT PRIMARY KEY,
   set_name TEXT NOT NULL,
@@ -186,6 +348,45 @@ CREATE TABLE card_data_sets (
   icon_svg_uri TEXT,
   scryfall_set_uri TEXT,
   updated_at TEXT NOT NULL
+, prices_first_seen_at TEXT, parent_set_code TEXT);
+CREATE TABLE collection_data_attachments (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  collection_item_id TEXT NOT NULL REFERENCES collection_data_collection_items(id) ON DELETE CASCADE,
+  kind TEXT NOT NULL DEFAULT 'photo',
+  file_name TEXT NOT NULL,
+  original_name TEXT,
+  mime_type TEXT,
+  byte_size INTEGER NOT NULL DEFAULT 0,
+  sha256 TEXT,
+  caption TEXT,
+  is_primary_image INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL
+);
+CREATE TABLE collection_data_audit_lines (
+  id TEXT PRIMARY KEY,
+  audit_id TEXT NOT NULL REFERENCES collection_data_audits(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  condition_code TEXT NOT NULL,
+  language TEXT NOT NULL,
+  expected_nonfoil INTEGER NOT NULL DEFAULT 0,
+  expected_foil INTEGER NOT NULL DEFAULT 0,
+  counted_nonfoil INTEGER,
+  counted_foil INTEGER,
+  counted_at TEXT,
+  created_at TEXT NOT NULL,
+  UNIQUE(audit_id, printing_id, condition_code, language)
+);
+CREATE TABLE collection_data_audits (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  location_id TEXT REFERENCES collection_data_locations(id) ON DELETE SET NULL,
+  location_name TEXT,
+  status TEXT NOT NULL DEFAULT 'open',
+  notes TEXT,
+  reconciled_at TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
 );
 CREATE TABLE collection_data_auth_accounts (
   id TEXT PRIMARY KEY,
@@ -224,12 +425,15 @@ CREATE TABLE collection_data_collection_items (
   quantity_foil INTEGER NOT NULL DEFAULT 0,
   condition_code TEXT NOT NULL DEFAULT 'NM',
   language TEXT NOT NULL DEFAULT 'en',
-  purchase_price NUMERIC,
+  purchase_price_cents NUMERIC,
   acquired_at TEXT,
   location_id TEXT REFERENCES collection_data_locations(id) ON DELETE SET NULL,
   notes TEXT,
   created_at TEXT NOT NULL,
-  updated_at TEXT NOT NULL
+  updated_at TEXT NOT NULL,
+  asking_price_cents NUMERIC,
+  trade_status TEXT,
+  purchase_currency TEXT
 );
 CREATE TABLE collection_data_collections (
   id TEXT PRIMARY KEY,
@@ -240,6 +444,47 @@ CREATE TABLE collection_data_collections (
   created_at TEXT NOT NULL,
   updated_at TEXT NOT NULL
 );
+CREATE TABLE collection_data_deck_cards (
+  id TEXT PRIMARY KEY,
+  deck_id TEXT NOT NULL REFERENCES collection_data_decks(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  board TEXT NOT NULL DEFAULT 'main',
+  quantity INTEGER NOT NULL DEFAULT 1,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL,
+  UNIQUE(deck_id, printing_id, board)
+);
+CREATE TABLE collection_data_decks (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  format TEXT,
+  description TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+CREATE TABLE collection_data_digital_items (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  platform TEXT NOT NULL,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
+  imported_at TEXT NOT NULL,
+  UNIQUE (collection_id, platform, printing_id)
+);
+CREATE TABLE collection_data_export_templates (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  format TEXT NOT NULL,
+  columns_json TEXT NOT NULL DEFAULT '[]',
+  header_template TEXT,
+  row_template TEXT,
+  footer_template TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
 CREATE TABLE collection_data_item_events (
   id TEXT PRIMARY KEY,
   collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
@@ -250,14 +495,98 @@ CREATE TABLE collection_data_item_events (
   quantity_foil_delta INTEGER NOT NULL DEFAULT 0,
   metadata_json TEXT,
   occurred_at TEXT NOT NULL,
+  created_at TEXT NOT NULL,
+  reason_code TEXT
+);
+CREATE TABLE collection_data_item_note_search_keys (
+  search_rowid INTEGER PRIMARY KEY,
+  collection_item_id TEXT NOT NULL UNIQUE
+);
+CREATE VIRTUAL TABLE collection_data_item_notes_fts USING fts5(
+  notes,
+  content = '',
+  contentless_delete = 1,
+  tokenize = 'unicode61 remove_diacritics 2'
+);
+CREATE TABLE collection_data_kit_checkouts (
+  id TEXT PRIMARY KEY,
+  kit_id TEXT NOT NULL REFERENCES collection_data_kits(id) ON DELETE CASCADE,
+  borrower_name TEXT NOT NULL,
+  note TEXT,
+  checked_out_at TEXT NOT NULL,
+  due_at TEXT,
+  checked_in_at TEXT
+);
+CREATE TABLE collection_data_kit_items (
+  id TEXT PRIMARY KEY,
+  kit_id TEXT NOT NULL REFERENCES collection_data_kits(id) ON DELETE CASCADE,
+  collection_item_id TEXT REFERENCES collection_data_collection_items(id) ON DELETE SET NULL,
+  label TEXT,
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
   created_at TEXT NOT NULL
 );
+CREATE TABLE collection_data_kits (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  description TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+CREATE TABLE collection_data_limited_event_cards (
+  id TEXT PRIMARY KEY,
+  event_id TEXT NOT NULL REFERENCES collection_data_limited_events(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL,
+  UNIQUE(event_id, printing_id)
+);
+CREATE TABLE collection_data_limited_events (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  format TEXT NOT NULL,
+  name TEXT,
+  set_code TEXT,
+  event_date TEXT,
+  wins INTEGER NOT NULL DEFAULT 0,
+  losses INTEGER NOT NULL DEFAULT 0,
+  draws INTEGER NOT NULL DEFAULT 0,
+  archetype TEXT,
+  notes TEXT,
+  merged_at TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
 CREATE TABLE collection_data_locations (
   id TEXT PRIMARY KEY,
   collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
   name TEXT NOT NULL,
   kind TEXT NOT NULL DEFAULT 'box',
   created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL,
+  insured_value_cap_cents NUMERIC
+);
+CREATE TABLE collection_data_price_alert_events (
+  id TEXT PRIMARY KEY,
+  alert_id TEXT NOT NULL REFERENCES collection_data_price_alerts(id) ON DELETE CASCADE,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  price_cents INTEGER NOT NULL,
+  threshold_cents INTEGER NOT NULL,
+  fired_at TEXT NOT NULL
+);
+CREATE TABLE collection_data_price_alerts (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  source_id TEXT NOT NULL,
+  direction TEXT NOT NULL,
+  threshold_cents INTEGER NOT NULL,
+  is_foil INTEGER NOT NULL DEFAULT 0,
+  triggered INTEGER NOT NULL DEFAULT 0,
+  last_fired_at TEXT,
+  created_at TEXT NOT NULL,
   updated_at TEXT NOT NULL
 );
 CREATE TABLE collection_data_profiles (
@@ -265,9 +594,100 @@ CREATE TABLE collection_data_profiles (
   display_name TEXT NOT NULL,
   owner_account_id TEXT REFERENCES collection_data_auth_accounts(id) ON DELETE SET NULL,
   is_local_profile INTEGER NOT NULL DEFAULT 1,
+  price_source_id TEXT,
+  valuation_currency TEXT,
+  retail_mode_enabled INTEGER NOT NULL DEFAULT 0,
+  pricing_rules_json TEXT,
+  money_locale TEXT,
+  display_locale TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL,
+  default_purchase_currency TEXT,
+  retain_zero_quantity_rows INTEGER NOT NULL DEFAULT 0,
+  condition_multipliers_json TEXT,
+  language_premiums_json TEXT
+);
+CREATE TABLE collection_data_removed_items (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  batch_id TEXT NOT NULL,
+  owned_item_id TEXT NOT NULL,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
+  condition_code TEXT NOT NULL DEFAULT 'NM',
+  language TEXT NOT NULL DEFAULT 'en',
+  purchase_price_cents NUMERIC,
+  acquired_at TEXT,
+  location_name TEXT,
+  notes TEXT,
+  tags_json TEXT NOT NULL DEFAULT '[]',
+  created_at TEXT NOT NULL,
+  removed_at TEXT NOT NULL,
+  expires_at TEXT NOT NULL,
+  purchase_currency TEXT,
+  asking_price_cents NUMERIC,
+  trade_status TEXT,
+  attachments_json TEXT NOT NULL DEFAULT '[]',
+  reservations_json TEXT NOT NULL DEFAULT '[]'
+);
+CREATE TABLE collection_data_reservations (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  collection_item_id TEXT NOT NULL REFERENCES collection_data_collection_items(id) ON DELETE CASCADE,
+  customer_name TEXT NOT NULL,
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
+  note TEXT,
+  status TEXT NOT NULL DEFAULT 'active',
+  sale_id TEXT,
   created_at TEXT NOT NULL,
   updated_at TEXT NOT NULL
 );
+CREATE TABLE collection_data_rollup_dirty (
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL,
+  PRIMARY KEY (collection_id, printing_id)
+);
+CREATE TABLE collection_data_rollups (
+  collection_id TEXT PRIMARY KEY REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  total_cards INTEGER NOT NULL DEFAULT 0,
+  total_foils INTEGER NOT NULL DEFAULT 0,
+  row_count INTEGER NOT NULL DEFAULT 0,
+  price_marker INTEGER NOT NULL DEFAULT -1,
+  mutation_counter INTEGER NOT NULL DEFAULT 0
+);
+CREATE TABLE collection_data_sale_lines (
+  id TEXT PRIMARY KEY,
+  sale_id TEXT NOT NULL REFERENCES collection_data_sales(id) ON DELETE CASCADE,
+  collection_item_id TEXT REFERENCES collection_data_collection_items(id) ON DELETE SET NULL,
+  printing_id TEXT NOT NULL REFERENCES card_data_printings(id) ON DELETE CASCADE,
+  condition_code TEXT NOT NULL DEFAULT 'NM',
+  quantity_nonfoil INTEGER NOT NULL DEFAULT 0,
+  quantity_foil INTEGER NOT NULL DEFAULT 0,
+  unit_price_cents NUMERIC NOT NULL,
+  line_total_cents NUMERIC NOT NULL,
+  reservation_id TEXT,
+  unit_cost_cents NUMERIC
+);
+CREATE TABLE collection_data_sales (
+  id TEXT PRIMARY KEY,
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  customer_name TEXT,
+  note TEXT,
+  currency TEXT NOT NULL,
+  total_amount_cents NUMERIC NOT NULL DEFAULT 0,
+  sold_at TEXT NOT NULL,
+  created_at TEXT NOT NULL
+);
+CREATE TABLE collection_data_set_rollups (
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  set_code TEXT NOT NULL,
+  total_cards INTEGER NOT NULL DEFAULT 0,
+  total_foils INTEGER NOT NULL DEFAULT 0,
+  row_count INTEGER NOT NULL DEFAULT 0,
+  PRIMARY KEY (collection_id, set_code)
+);
 CREATE TABLE collection_data_tags (
   id TEXT PRIMARY KEY,
   collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
@@ -275,6 +695,70 @@ CREATE TABLE collection_data_tags (
   color_hex TEXT,
   created_at TEXT NOT NULL
 );
+CREATE TABLE collection_data_valuation_cache (
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  cache_key TEXT NOT NULL,
+  mutation_counter INTEGER NOT NULL,
+  price_version TEXT NOT NULL,
+  preferences_key TEXT NOT NULL,
+  payload_json TEXT NOT NULL,
+  computed_at TEXT NOT NULL,
+  PRIMARY KEY (collection_id, cache_key)
+);
+CREATE TABLE collection_data_value_rollups (
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  printing_id TEXT NOT NULL,
+  channel_id TEXT NOT NULL,
+  total_value_cents INTEGER NOT NULL DEFAULT 0,
+  PRIMARY KEY (collection_id, printing_id, channel_id)
+);
+CREATE TABLE collection_data_value_snapshots (
+  collection_id TEXT NOT NULL REFERENCES collection_data_collections(id) ON DELETE CASCADE,
+  snapshot_date TEXT NOT NULL,
+  market_value_cents INTEGER NOT NULL,
+  low_value_cents INTEGER NOT NULL,
+  buylist_value_cents INTEGER NOT NULL,
+  purchase_cost_cents INTEGER NOT NULL,
+  total_cards INTEGER NOT NULL,
+  captured_at TEXT NOT NULL,
+  PRIMARY KEY (collection_id, snapshot_date)
+);
+CREATE TABLE system_data_jobs (
+  id TEXT PRIMARY KEY,
+  kind TEXT NOT NULL,
+  dedupe_key TEXT NOT NULL,
+  payload_json TEXT,
+  status TEXT NOT NULL DEFAULT 'queued',
+  phase TEXT NOT NULL DEFAULT 'queued',
+  progress_json TEXT,
+  result_json TEXT,
+  error TEXT,
+  cancel_requested INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL,
+  started_at TEXT,
+  finished_at TEXT
+);
+CREATE TABLE system_data_image_prefetch_queue (
+  url TEXT PRIMARY KEY,
+  status TEXT NOT NULL DEFAULT 'queued',
+  attempts INTEGER NOT NULL DEFAULT 0,
+  file_name TEXT,
+  bytes INTEGER,
+  last_error TEXT,
+  queued_at TEXT NOT NULL,
+  fetched_at TEXT
+);
+CREATE TABLE system_data_performance_metrics (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  metric_kind TEXT NOT NULL,
+  metric_name TEXT NOT NULL,
+  app_version TEXT NOT NULL,
+  duration_ms REAL NOT NULL,
+  rows_processed INTEGER,
+  is_slow INTEGER NOT NULL DEFAULT 0,
+  detail TEXT,
+  recorded_at TEXT NOT NULL
+);
 CREATE TABLE system_data_sync_client_sync_state (
   client_id TEXT NOT NULL,
   dataset_name TEXT NOT NULL,
@@ -300,6 +784,7 @@ CREATE TABLE system_data_sync_dataset_versions (
   dataset_name TEXT NOT NULL,
   build_version TEXT NOT NULL,
   state_hash TEXT,
+  state_accumulator TEXT,
   record_count INTEGER,
   created_at TEXT NOT NULL,
   UNIQUE(source_id, dataset_name, build_version)
@@ -316,6 +801,15 @@ CREATE TABLE system_data_sync_patch_apply_history (
   error_message TEXT,
   applied_at TEXT NOT NULL
 );
+CREATE TABLE system_data_sync_notifications (
+  id TEXT PRIMARY KEY,
+  kind TEXT NOT NULL,
+  title TEXT NOT NULL,
+  body TEXT,
+  payload_json TEXT,
+  created_at TEXT NOT NULL,
+  read_at TEXT
+);
 CREATE TABLE system_data_sync_patches (
   id TEXT PRIMARY KEY,
   source_id TEXT NOT NULL REFERENCES system_data_sync_data_sources(id) ON DELETE CASCADE,
@@ -330,6 +824,11 @@ CREATE TABLE system_data_sync_patches (
   artifact_uri TEXT,
   created_at TEXT NOT NULL
 );
+CREATE TABLE system_data_sync_settings (
+  key TEXT PRIMARY KEY,
+  value TEXT,
+  updated_at TEXT NOT NULL
+);
 CREATE INDEX idx_collection_data_auth_sessions_account
   ON collection_data_auth_sessions(account_id, created_at DESC);
 CREATE UNIQUE INDEX idx_collection_data_profiles_display_name
@@ -346,14 +845,75 @@ CREATE INDEX idx_collection_data_items_collection
   ON collection_data_collection_items(collection_id, updated_at DESC);
 CREATE INDEX idx_collection_data_items_printing
   ON collection_data_collection_items(printing_id);
+CREATE INDEX idx_collection_data_items_collection_copies
+  ON collection_data_collection_items(collection_id, (quantity_nonfoil + quantity_foil));
 CREATE UNIQUE INDEX idx_collection_data_tags_collection_name
   ON collection_data_tags(collection_id, name COLLATE NOCASE);
 CREATE INDEX idx_collection_data_item_events_collection_time
   ON collection_data_item_events(collection_id, occurred_at DESC);
 CREATE INDEX idx_collection_data_item_events_printing
   ON collection_data_item_events(printing_id, occurred_at DESC);
+CREATE INDEX idx_collection_data_item_events_reason
+  ON collection_data_item_events(collection_id, reason_code, occurred_at DESC);
+CREATE INDEX idx_collection_data_removed_items_collection
+  ON collection_data_removed_items(collection_id, removed_at);
+CREATE INDEX idx_collection_data_removed_items_expires
+  ON collection_data_removed_items(expires_at);
+CREATE INDEX idx_collection_data_reservations_item
+  ON collection_data_reservations(collection_item_id, status);
+CREATE INDEX idx_collection_data_reservations_collection
+  ON collection_data_reservations(collection_id, status, created_at);
+CREATE INDEX idx_collection_data_sales_collection
+  ON collection_data_sales(collection_id, sold_at);
+CREATE INDEX idx_collection_data_sale_lines_sale
+  ON collection_data_sale_lines(sale_id);
+CREATE UNIQUE INDEX idx_collection_data_kits_collection_name
+  ON collection_data_kits(collection_id, name);
+CREATE INDEX idx_collection_data_kit_items_kit
+  ON collection_data_kit_items(kit_id);
+CREATE INDEX idx_collection_data_kit_items_item
+  ON collection_data_kit_items(collection_item_id);
+CREATE INDEX idx_collection_data_kit_checkouts_kit
+  ON collection_data_kit_checkouts(kit_id, checked_in_at);
+CREATE INDEX idx_collection_data_attachments_item
+  ON collection_data_attachments(collection_item_id);
+CREATE UNIQUE INDEX idx_collection_data_attachments_primary_image
+  ON collection_data_attachments(collection_item_id)
+  WHERE is_primary_image = 1;
+CREATE INDEX idx_collection_data_value_rollups_channel
+  ON collection_data_value_rollups(collection_id, channel_id);
+CREATE INDEX idx_collection_data_limited_events_collection
+  ON collection_data_limited_events(collection_id, event_date DESC);
+CREATE INDEX idx_collection_data_items_trade_status
+  ON collection_data_collection_items(collection_id, trade_status);
+CREATE INDEX idx_collection_data_audits_collection
+  ON collection_data_audits(collection_id, created_at DESC);
+CREATE UNIQUE INDEX idx_collection_data_export_templates_collection_name
+  ON collection_data_export_templates(collection_id, name);
+CREATE UNIQUE INDEX idx_collection_data_decks_collection_name
+  ON collection_data_decks(collection_id, name);
+CREATE INDEX idx_collection_data_deck_cards_printing
+  ON collection_data_deck_cards(printing_id);
+CREATE INDEX idx_system_data_jobs_status_created
+  ON system_data_jobs(status, created_at);
+CREATE INDEX idx_system_data_jobs_dedupe
+  ON system_data_jobs(dedupe_key, status);
+CREATE INDEX idx_system_data_image_prefetch_queue_status
+  ON system_data_image_prefetch_queue(status, queued_at);
+CREATE INDEX idx_system_data_performance_metrics_name
+  ON system_data_performance_metrics(metric_name, recorded_at DESC);
+CREATE INDEX idx_system_data_performance_metrics_time
+  ON system_data_performance_metrics(recorded_at);
 CREATE INDEX idx_card_data_cards_name
   ON card_data_cards(name COLLATE NOCASE);
+CREATE INDEX idx_card_data_cards_name_normalized
+  ON card_data_cards(name_normalized);
+CREATE INDEX idx_card_data_combo_pieces_oracle
+  ON card_data_combo_pieces(oracle_id);
+CREATE INDEX idx_card_data_oracle_changes_card
+  ON card_data_oracle_changes(card_id, changed_at DESC);
+CREATE INDEX idx_card_data_oracle_changes_changed_at
+  ON card_data_oracle_changes(changed_at DESC);
 CREATE INDEX idx_card_data_printings_card
   ON card_data_printings(card_id);
 CREATE INDEX idx_card_data_printings_set_collector
@@ -377,4 +937,300 @@ CREATE INDEX idx_card_data_card_prices_sync_version
   ON card_data_card_prices(sync_version, captured_ymd DESC);
 CREATE INDEX idx_card_data_card_prices_printing_captured_at
   ON card_data_card_prices(printing_id, captured_at DESC);
+CREATE INDEX idx_card_data_card_prices_series_time
+  ON card_data_card_prices(printing_id, condition_id, finish_id, captured_at DESC);
+CREATE INDEX idx_system_data_sync_notifications_time
+  ON system_data_sync_notifications(created_at DESC);
+CREATE INDEX idx_card_data_sets_released
+  ON card_data_sets(released_at);
+CREATE INDEX idx_card_data_sets_parent
+  ON card_data_sets(parent_set_code);
+CREATE INDEX idx_collection_data_price_alerts_collection
+  ON collection_data_price_alerts(collection_id);
+CREATE INDEX idx_collection_data_price_alert_events_collection
+  ON collection_data_price_alert_events(collection_id, fired_at);
+CREATE INDEX idx_collection_data_digital_items_printing
+  ON collection_data_digital_items(printing_id);
+CREATE TRIGGER trg_collection_items_ai_rollups
+AFTER INSERT ON collection_data_collection_items
+BEGIN
+  INSERT INTO collection_data_rollups (collection_id, total_cards, total_foils, row_count)
+  VALUES (
+    NEW.collection_id,
+    NEW.quantity_nonfoil + NEW.quantity_foil,
+    NEW.quantity_foil,
+    CASE WHEN NEW.quantity_nonfoil + NEW.quantity_foil > 0 THEN 1 ELSE 0 END
+  )
+  ON CONFLICT(collection_id) DO UPDATE SET
+    total_cards = total_cards + excluded.total_cards,
+    total_foils = total_foils + excluded.total_foils,
+    row_count = row_count + excluded.row_count;
+  INSERT INTO collection_data_set_rollups (collection_id, set_code, total_cards, total_foils, row_count)
+  VALUES (
+    NEW.collection_id,
+    IFNULL((SELECT set_code FROM card_data_printings WHERE id = NEW.printing_id), ''),
+    NEW.quantity_nonfoil + NEW.quantity_foil,
+    NEW.quantity_foil,
+    CASE WHEN NEW.quantity_nonfoil + NEW.quantity_foil > 0 THEN 1 ELSE 0 END
+  )
+  ON CONFLICT(collection_id, set_code) DO UPDATE SET
+    total_cards = total_cards + excluded.total_cards,
+    total_foils = total_foils + excluded.total_foils,
+    row_count = row_count + excluded.row_count;
+  INSERT OR IGNORE INTO collection_data_rollup_dirty (collection_id, printing_id)
+  VALUES (NEW.collection_id, NEW.printing_id);
+END;
+CREATE TRIGGER trg_collection_items_ad_rollups
+AFTER DELETE ON collection_data_collection_items
+BEGIN
+  UPDATE collection_data_rollups
+  SET total_cards = total_cards - (OLD.quantity_nonfoil + OLD.quantity_foil),
+      total_foils = total_foils - OLD.quantity_foil,
+      row_count = row_count - CASE WHEN OLD.quantity_nonfoil + OLD.quantity_foil > 0 THEN 1 ELSE 0 END
+  WHERE collection_id = OLD.collection_id;
+  UPDATE collection_data_set_rollups
+  SET total_cards = total_cards - (OLD.quantity_nonfoil + OLD.quantity_foil),
+      total_foils = total_foils - OLD.quantity_foil,
+      row_count = row_count - CASE WHEN OLD.quantity_nonfoil + OLD.quantity_foil > 0 THEN 1 ELSE 0 END
+  WHERE collection_id = OLD.collection_id
+    AND set_code = IFNULL((SELECT set_code FROM card_data_printings WHERE id = OLD.printing_id), '');
+  DELETE FROM collection_data_set_rollups
+  WHERE collection_id = OLD.collection_id
+    AND total_cards <= 0
+    AND row_count <= 0;
+  INSERT OR IGNORE INTO collection_data_rollup_dirty (collection_id, printing_id)
+  SELECT OLD.collection_id, OLD.printing_id
+  WHERE EXISTS (SELECT 1 FROM collection_data_collections WHERE id = OLD.collection_id);
+END;
+CREATE TRIGGER trg_collection_items_au_rollups
+AFTER UPDATE OF quantity_nonfoil, quantity_foil, printing_id, collection_id ON collection_data_collection_items
+BEGIN
+  UPDATE collection_data_rollups
+  SET total_cards = total_cards - (OLD.quantity_nonfoil + OLD.quantity_foil),
+      total_foils = total_foils - OLD.quantity_foil,
+      row_count = row_count - CASE WHEN OLD.quantity_nonfoil + OLD.quantity_foil > 0 THEN 1 ELSE 0 END
+  WHERE collection_id = OLD.collection_id;
+  UPDATE collection_data_set_rollups
+  SET total_cards = total_cards - (OLD.quantity_nonfoil + OLD.quantity_foil),
+      total_foils = total_foils - OLD.quantity_foil,
+      row_count = row_count - CASE WHEN OLD.quantity_nonfoil + OLD.quantity_foil > 0 THEN 1 ELSE 0 END
+  WHERE collection_id = OLD.collection_id
+    AND set_code = IFNULL((SELECT set_code FROM card_data_printings WHERE id = OLD.printing_id), '');
+  INSERT INTO collection_data_rollups (collection_id, total_cards, total_foils, row_count)
+  VALUES (
+    NEW.collection_id,
+    NEW.quantity_nonfoil + NEW.quantity_foil,
+    NEW.quantity_foil,
+    CASE WHEN NEW.quantity_nonfoil + NEW.quantity_foil > 0 THEN 1 ELSE 0 END
+  )
+  ON CONFLICT(collection_id) DO UPDATE SET
+    total_cards = total_cards + excluded.total_cards,
+    total_foils = total_foils + excluded.total_foils,
+    row_count = row_count + excluded.row_count;
+  INSERT INTO collection_data_set_rollups (collection_id, set_code, total_cards, total_foils, row_count)
+  VALUES (
+    NEW.collection_id,
+    IFNULL((SELECT set_code FROM card_data_printings WHERE id = NEW.printing_id), ''),
+    NEW.quantity_nonfoil + NEW.quantity_foil,
+    NEW.quantity_foil,
+    CASE WHEN NEW.quantity_nonfoil + NEW.quantity_foil > 0 THEN 1 ELSE 0 END
+  )
+  ON CONFLICT(collection_id, set_code) DO UPDATE SET
+    total_cards = total_cards + excluded.total_cards,
+    total_foils = total_foils + excluded.total_foils,
+    row_count = row_count + excluded.row_count;
+  DELETE FROM collection_data_set_rollups
+  WHERE collection_id = OLD.collection_id
+    AND total_cards <= 0
+    AND row_count <= 0;
+  INSERT OR IGNORE INTO collection_data_rollup_dirty (collection_id, printing_id)
+  VALUES (OLD.collection_id, OLD.printing_id);
+  INSERT OR IGNORE INTO collection_data_rollup_dirty (collection_id, printing_id)
+  VALUES (NEW.collection_id, NEW.printing_id);
+END;
+CREATE TRIGGER trg_collection_items_au_rollup_condition
+AFTER UPDATE OF condition_code ON collection_data_collection_items
+BEGIN
+  INSERT OR IGNORE INTO collection_data_rollup_dirty (collection_id, printing_id)
+  VALUES (NEW.collection_id, NEW.printing_id);
+END;
+CREATE TRIGGER trg_collection_items_ai_mutation
+AFTER INSERT ON collection_data_collection_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+CREATE TRIGGER trg_collection_items_ad_mutation
+AFTER DELETE ON collection_data_collection_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = OLD.collection_id;
+END;
+CREATE TRIGGER trg_collection_items_au_mutation
+AFTER UPDATE ON collection_data_collection_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id IN (OLD.collection_id, NEW.collection_id);
+END;
+CREATE TRIGGER trg_collection_item_tags_ai_mutation
+AFTER INSERT ON collection_data_collection_item_tags
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_tags WHERE id = NEW.tag_id);
+END;
+CREATE TRIGGER trg_collection_item_tags_ad_mutation
+AFTER DELETE ON collection_data_collection_item_tags
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_tags WHERE id = OLD.tag_id);
+END;
+CREATE TRIGGER trg_collection_tags_au_mutation
+AFTER UPDATE OF name ON collection_data_tags
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+CREATE TRIGGER trg_collection_kits_ai_mutation
+AFTER INSERT ON collection_data_kits
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+CREATE TRIGGER trg_collection_kits_ad_mutation
+AFTER DELETE ON collection_data_kits
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = OLD.collection_id;
+END;
+CREATE TRIGGER trg_collection_kits_au_mutation
+AFTER UPDATE OF name ON collection_data_kits
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+CREATE TRIGGER trg_collection_kit_items_ai_mutation
+AFTER INSERT ON collection_data_kit_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_kits WHERE id = NEW.kit_id);
+END;
+CREATE TRIGGER trg_collection_kit_items_ad_mutation
+AFTER DELETE ON collection_data_kit_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_kits WHERE id = OLD.kit_id);
+END;
+CREATE TRIGGER trg_collection_kit_items_au_mutation
+AFTER UPDATE ON collection_data_kit_items
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_kits WHERE id = NEW.kit_id);
+END;
+CREATE TRIGGER trg_collection_decks_ai_mutation
+AFTER INSERT ON collection_data_decks
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+CREATE TRIGGER trg_collection_decks_ad_mutation
+AFTER DELETE ON collection_data_decks
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = OLD.collection_id;
+END;
+CREATE TRIGGER trg_collection_decks_au_mutation
+AFTER UPDATE OF name ON collection_data_decks
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+CREATE TRIGGER trg_collection_deck_cards_ai_mutation
+AFTER INSERT ON collection_data_deck_cards
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_decks WHERE id = NEW.deck_id);
+END;
+CREATE TRIGGER trg_collection_deck_cards_ad_mutation
+AFTER DELETE ON collection_data_deck_cards
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_decks WHERE id = OLD.deck_id);
+END;
+CREATE TRIGGER trg_collection_deck_cards_au_mutation
+AFTER UPDATE ON collection_data_deck_cards
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = (SELECT collection_id FROM collection_data_decks WHERE id = NEW.deck_id);
+END;
+CREATE TRIGGER trg_collection_locations_au_mutation
+AFTER UPDATE OF name, insured_value_cap_cents ON collection_data_locations
+BEGIN
+  UPDATE collection_data_rollups SET mutation_counter = mutation_counter + 1
+  WHERE collection_id = NEW.collection_id;
+END;
+CREATE TRIGGER trg_card_data_cards_ai_search
+AFTER INSERT ON card_data_cards
+BEGIN
+  INSERT OR IGNORE INTO card_data_card_search_keys (card_id) VALUES (NEW.id);
+  INSERT INTO card_data_cards_fts (rowid, name, type_line, oracle_text)
+  VALUES (
+    (SELECT search_rowid FROM card_data_card_search_keys WHERE card_id = NEW.id),
+    NEW.name,
+    NEW.type_line,
+    NEW.oracle_text
+  );
+END;
+CREATE TRIGGER trg_card_data_cards_ad_search
+AFTER DELETE ON card_data_cards
+BEGIN
+  DELETE FROM card_data_cards_fts
+  WHERE rowid = (SELECT search_rowid FROM card_data_card_search_keys WHERE card_id = OLD.id);
+  DELETE FROM card_data_card_search_keys WHERE card_id = OLD.id;
+END;
+CREATE TRIGGER trg_card_data_cards_au_search
+AFTER UPDATE OF name, type_line, oracle_text ON card_data_cards
+WHEN OLD.name IS NOT NEW.name
+  OR OLD.type_line IS NOT NEW.type_line
+  OR OLD.oracle_text IS NOT NEW.oracle_text
+BEGIN
+  UPDATE card_data_cards_fts
+  SET name = NEW.name, type_line = NEW.type_line, oracle_text = NEW.oracle_text
+  WHERE rowid = (SELECT search_rowid FROM card_data_card_search_keys WHERE card_id = NEW.id);
+END;
+CREATE TRIGGER trg_collection_items_ai_search
+AFTER INSERT ON collection_data_collection_items
+WHEN trim(COALESCE(NEW.notes, '')) <> ''
+BEGIN
+  INSERT OR IGNORE INTO collection_data_item_note_search_keys (collection_item_id) VALUES (NEW.id);
+  INSERT INTO collection_data_item_notes_fts (rowid, notes)
+  VALUES (
+    (SELECT search_rowid FROM collection_data_item_note_search_keys WHERE collection_item_id = NEW.id),
+    NEW.notes
+  );
+END;
+CREATE TRIGGER trg_collection_items_ad_search
+AFTER DELETE ON collection_data_collection_items
+BEGIN
+  DELETE FROM collection_data_item_notes_fts
+  WHERE rowid = (
+    SELECT search_rowid FROM collection_data_item_note_search_keys WHERE collection_item_id = OLD.id
+  );
+  DELETE FROM collection_data_item_note_search_keys WHERE collection_item_id = OLD.id;
+END;
+CREATE TRIGGER trg_collection_items_au_search
+AFTER UPDATE OF notes ON collection_data_collection_items
+WHEN OLD.notes IS NOT NEW.notes
+BEGIN
+  DELETE FROM collection_data_item_notes_fts
+  WHERE rowid = (
+    SELECT search_rowid FROM collection_data_item_note_search_keys WHERE collection_item_id = OLD.id
+  );
+  DELETE FROM collection_data_item_note_search_keys WHERE collection_item_id = OLD.id;
+  INSERT INTO collection_data_item_note_search_keys (collection_item_id)
+  SELECT NEW.id WHERE trim(COALESCE(NEW.notes, '')) <> '';
+  INSERT INTO collection_data_item_notes_fts (rowid, notes)
+  SELECT search_rowid, NEW.notes
+  FROM collection_data_item_note_search_keys
+  WHERE collection_item_id = NEW.id;
+END;
 PRAGMA foreign_keys = ON;
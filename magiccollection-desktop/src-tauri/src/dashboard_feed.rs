@@ -0,0 +1,542 @@
+// Dashboard feed: an opt-in local HTTP endpoint for home dashboards (Home Assistant, Grafana).
+// `GET /summary` returns each profile's copy count and value as JSON; `GET /events` streams
+// server-sent events when a collection changes, prices are synced or a price alert fires.
+// Every request carries the feed token, as `Authorization: Bearer <token>` or `?token=<token>`.
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, Runtime};
+use uuid::Uuid;
+
+use crate::{
+  cents_to_price, current_price_version, load_cached_valuation, load_collection_valuation,
+  load_profile_price_preferences, now_iso, open_database, read_sync_setting, write_sync_setting, AppState,
+};
+
+pub(crate) const DASHBOARD_FEED_ENABLED_SETTING: &str = "dashboard_feed_enabled";
+pub(crate) const DASHBOARD_FEED_PORT_SETTING: &str = "dashboard_feed_port";
+pub(crate) const DASHBOARD_FEED_LAN_SETTING: &str = "dashboard_feed_allow_lan";
+pub(crate) const DASHBOARD_FEED_TOKEN_SETTING: &str = "dashboard_feed_token";
+pub(crate) const DASHBOARD_FEED_DEFAULT_PORT: u16 = 8765;
+// How often an open event stream checks the database for changes.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+// Comment lines keep proxies and clients from timing out an idle stream.
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(15);
+const ACCEPT_IDLE_SLEEP: Duration = Duration::from_millis(200);
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_REQUEST_HEADERS: usize = 64;
+// Request line and headers together; anything longer is cut off and read as malformed.
+const MAX_REQUEST_BYTES: u64 = 8 * 1024;
+// Open connections, event streams included; more are answered 503 and closed.
+const MAX_CONNECTIONS: usize = 16;
+// 500 body when the workspace database cannot be opened; the path stays out of the response.
+const DATABASE_UNAVAILABLE: &str = "The workspace database could not be opened.";
+
+#[derive(Clone)]
+pub(crate) struct DashboardFeedSettings {
+  pub(crate) enabled: bool,
+  pub(crate) port: u16,
+  // Listen on every interface instead of loopback only, for dashboards on another machine.
+  pub(crate) allow_lan: bool,
+  pub(crate) token: Option<String>,
+}
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DashboardFeedStatusDto {
+  enabled: bool,
+  port: u16,
+  allow_lan: bool,
+  token: Option<String>,
+  running: bool,
+  // Bound address while running, e.g. 127.0.0.1:8765.
+  address: Option<String>,
+  last_error: Option<String>,
+}
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct ProfileSummaryDto {
+  profile_id: String,
+  name: String,
+  currency: String,
+  price_source_id: String,
+  copy_count: i64,
+  total_value: f64,
+  total_value_formatted: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FeedSummaryDto {
+  generated_at: String,
+  price_version: String,
+  profiles: Vec<ProfileSummaryDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PriceAlertEventDto {
+  alert_id: String,
+  profile_id: String,
+  scryfall_id: String,
+  name: String,
+  direction: String,
+  foil: bool,
+  price: f64,
+  threshold: f64,
+  fired_at: String,
+}
+
+struct RunningFeed {
+  address: SocketAddr,
+  stop: Arc<AtomicBool>,
+  accept_loop: thread::JoinHandle<()>,
+}
+
+#[derive(Default)]
+pub(crate) struct DashboardFeedState {
+  running: Mutex<Option<RunningFeed>>,
+  last_error: Mutex<Option<String>>,
+}
+
+pub(crate) fn read_settings(connection: &Connection) -> Result<DashboardFeedSettings, String> {
+  let flag = |value: Option<String>| value.map(|value| value == "1" || value.eq_ignore_ascii_case("true"));
+  Ok(DashboardFeedSettings {
+    enabled: flag(read_sync_setting(connection, DASHBOARD_FEED_ENABLED_SETTING)?).unwrap_or(false),
+    port: read_sync_setting(connection, DASHBOARD_FEED_PORT_SETTING)?
+      .and_then(|value| value.trim().parse::<u16>().ok())
+      .unwrap_or(DASHBOARD_FEED_DEFAULT_PORT),
+    allow_lan: flag(read_sync_setting(connection, DASHBOARD_FEED_LAN_SETTING)?).unwrap_or(false),
+    token: read_sync_setting(connection, DASHBOARD_FEED_TOKEN_SETTING)?.filter(|value| !value.trim().is_empty()),
+  })
+}
+
+// A feed that is switched on always has a token; `regenerate_token` replaces the current one.
+pub(crate) fn write_settings(
+  connection: &Connection,
+  settings: &DashboardFeedSettings,
+  regenerate_token: bool,
+) -> Result<(), String> {
+  if settings.port < 1024 {
+    return Err("Dashboard feed port must be between 1024 and 65535.".to_string());
+  }
+  let token = match &settings.token {
+    Some(token) if !regenerate_token => Some(token.clone()),
+    _ if settings.enabled || regenerate_token => Some(Uuid::new_v4().simple().to_string()),
+    _ => None,
+  };
+  write_sync_setting(
+    connection,
+    DASHBOARD_FEED_ENABLED_SETTING,
+    Some(if settings.enabled { "1" } else { "0" }),
+  )?;
+  write_sync_setting(connection, DASHBOARD_FEED_PORT_SETTING, Some(&settings.port.to_string()))?;
+  write_sync_setting(
+    connection,
+    DASHBOARD_FEED_LAN_SETTING,
+    Some(if settings.allow_lan { "1" } else { "0" }),
+  )?;
+  write_sync_setting(connection, DASHBOARD_FEED_TOKEN_SETTING, token.as_deref())
+}
+
+pub(crate) fn status(state: &DashboardFeedState, settings: &DashboardFeedSettings) -> DashboardFeedStatusDto {
+  let address = state
+    .running
+    .lock()
+    .ok()
+    .and_then(|running| running.as_ref().map(|feed| feed.address.to_string()));
+  DashboardFeedStatusDto {
+    enabled: settings.enabled,
+    port: settings.port,
+    allow_lan: settings.allow_lan,
+    token: settings.token.clone(),
+    running: address.is_some(),
+    address,
+    last_error: state.last_error.lock().ok().and_then(|error| error.clone()),
+  }
+}
+
+// Stops the running listener and starts one for the current workspace's settings when enabled.
+// Called at startup, after a settings change and after switching workspaces.
+pub(crate) fn apply_settings<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+  let feed = app.state::<DashboardFeedState>();
+  let mut running = feed.running.lock().map_err(|e| e.to_string())?;
+  if let Some(previous) = running.take() {
+    previous.stop.store(true, Ordering::SeqCst);
+    // The port is free again once the listener thread has dropped its socket.
+    let _ = previous.accept_loop.join();
+  }
+  let connection = open_database(&app.state::<AppState>().db_path())?;
+  let settings = read_settings(&connection)?;
+  drop(connection);
+  let result = match settings.token.clone().filter(|_| settings.enabled) {
+    Some(token) => start(app.clone(), &settings, token).map(|feed| {
+      *running = Some(feed);
+    }),
+    None => Ok(()),
+  };
+  if let Ok(mut last_error) = feed.last_error.lock() {
+    *last_error = result.as_ref().err().cloned();
+  }
+  result
+}
+
+fn start<R: Runtime>(app: AppHandle<R>, settings: &DashboardFeedSettings, token: String) -> Result<RunningFeed, String> {
+  let host = if settings.allow_lan { "0.0.0.0" } else { "127.0.0.1" };
+  let listener = TcpListener::bind((host, settings.port))
+    .map_err(|e| format!("Could not listen on {}:{}: {}", host, settings.port, e))?;
+  // Non-blocking accepts let the loop notice the stop flag.
+  listener.set_nonblocking(true).map_err(|e| e.to_string())?;
+  let address = listener.local_addr().map_err(|e| e.to_string())?;
+  let stop = Arc::new(AtomicBool::new(false));
+  let listener_stop = stop.clone();
+  let open_connections = Arc::new(AtomicUsize::new(0));
+  let accept_loop = thread::spawn(move || {
+    while !listener_stop.load(Ordering::SeqCst) {
+      match listener.accept() {
+        Ok((mut stream, _)) => {
+          let Some(slot) = ConnectionSlot::acquire(&open_connections) else {
+            let _ = stream.set_nonblocking(false);
+            let _ = stream.set_write_timeout(Some(REQUEST_READ_TIMEOUT));
+            let _ = write_error(&mut stream, "503 Service Unavailable", "Too many dashboard feed connections.");
+            continue;
+          };
+          let app = app.clone();
+          let token = token.clone();
+          let stop = listener_stop.clone();
+          thread::spawn(move || {
+            let _slot = slot;
+            if let Err(error) = handle_connection(&app, stream, &token, &stop) {
+              log::debug!("dashboard feed connection closed: {}", error);
+            }
+          });
+        }
+        Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => thread::sleep(ACCEPT_IDLE_SLEEP),
+        Err(error) => {
+          log::warn!("dashboard feed accept failed: {}", error);
+          thread::sleep(ACCEPT_IDLE_SLEEP);
+        }
+      }
+    }
+  });
+  Ok(RunningFeed {
+    address,
+    stop,
+    accept_loop,
+  })
+}
+
+// Counts one open connection until dropped, including when its handler panics.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+  fn acquire(open_connections: &Arc<AtomicUsize>) -> Option<Self> {
+    open_connections
+      .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |open| (open < MAX_CONNECTIONS).then_some(open + 1))
+      .ok()
+      .map(|_| ConnectionSlot(open_connections.clone()))
+  }
+}
+
+impl Drop for ConnectionSlot {
+  fn drop(&mut self) {
+    self.0.fetch_sub(1, Ordering::SeqCst);
+  }
+}
+
+struct FeedRequest {
+  method: String,
+  path: String,
+  query: Vec<(String, String)>,
+  bearer: Option<String>,
+}
+
+impl FeedRequest {
+  fn query_value(&self, key: &str) -> Option<&str> {
+    self
+      .query
+      .iter()
+      .find(|(name, _)| name == key)
+      .map(|(_, value)| value.as_str())
+      .filter(|value| !value.is_empty())
+  }
+}
+
+fn read_request(stream: &TcpStream) -> Result<FeedRequest, String> {
+  let mut reader = BufReader::new(stream.take(MAX_REQUEST_BYTES));
+  let mut request_line = String::new();
+  reader.read_line(&mut request_line).map_err(|e| e.to_string())?;
+  let mut parts = request_line.split_whitespace();
+  let method = parts.next().unwrap_or_default().to_string();
+  let target = parts.next().unwrap_or_default();
+  let (path, query) = target.split_once('?').unwrap_or((target, ""));
+  let query = query
+    .split('&')
+    .filter(|pair| !pair.is_empty())
+    .map(|pair| {
+      let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+      (name.to_string(), value.to_string())
+    })
+    .collect();
+
+  let mut bearer = None;
+  for _ in 0..MAX_REQUEST_HEADERS {
+    let mut line = String::new();
+    if reader.read_line(&mut line).map_err(|e| e.to_string())? == 0 || line.trim().is_empty() {
+      break;
+    }
+    if let Some((name, value)) = line.split_once(':') {
+      if name.trim().eq_ignore_ascii_case("authorization") {
+        bearer = value.trim().strip_prefix("Bearer ").map(|token| token.trim().to_string());
+      }
+    }
+  }
+  Ok(FeedRequest {
+    method,
+    path: path.to_string(),
+    query,
+    bearer,
+  })
+}
+
+fn write_response(stream: &mut TcpStream, status: &str, content_type: &str, body: &str) -> Result<(), String> {
+  write!(
+    stream,
+    "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nAccess-Control-Allow-Origin: *\r\nConnection: close\r\n\r\n{}",
+    status,
+    content_type,
+    body.len(),
+    body
+  )
+  .map_err(|e| e.to_string())
+}
+
+fn write_error(stream: &mut TcpStream, status: &str, message: &str) -> Result<(), String> {
+  let body = serde_json::json!({ "error": message }).to_string();
+  write_response(stream, status, "application/json", &body)
+}
+
+// Compares digests without an early exit, so response timing says nothing about how much of a
+// guessed token was right.
+fn tokens_match(presented: &str, token: &str) -> bool {
+  Sha256::digest(presented.as_bytes())
+    .iter()
+    .zip(Sha256::digest(token.as_bytes()).iter())
+    .fold(0_u8, |difference, (a, b)| difference | (a ^ b))
+    == 0
+}
+
+fn handle_connection<R: Runtime>(
+  app: &AppHandle<R>,
+  mut stream: TcpStream,
+  token: &str,
+  stop: &AtomicBool,
+) -> Result<(), String> {
+  stream.set_nonblocking(false).map_err(|e| e.to_string())?;
+  stream.set_read_timeout(Some(REQUEST_READ_TIMEOUT)).map_err(|e| e.to_string())?;
+  let request = read_request(&stream)?;
+  if request.method != "GET" {
+    return write_error(&mut stream, "405 Method Not Allowed", "Only GET is supported.");
+  }
+  let presented = request.bearer.as_deref().or_else(|| request.query_value("token"));
+  if !presented.is_some_and(|presented| tokens_match(presented, token)) {
+    return write_error(&mut stream, "401 Unauthorized", "Missing or wrong dashboard feed token.");
+  }
+  let profile_filter = request.query_value("profile").map(str::to_string);
+  match request.path.as_str() {
+    "/summary" => {
+      let summary = open_database(&app.state::<AppState>().db_path())
+        .map_err(|_| DATABASE_UNAVAILABLE.to_string())
+        .and_then(|connection| load_summary(&connection, profile_filter.as_deref()));
+      match summary {
+        Ok(summary) => {
+          let body = serde_json::to_string(&summary).map_err(|e| e.to_string())?;
+          write_response(&mut stream, "200 OK", "application/json", &body)
+        }
+        Err(error) => write_error(&mut stream, "500 Internal Server Error", &error),
+      }
+    }
+    "/events" => stream_events(app, stream, profile_filter.as_deref(), stop),
+    _ => write_error(&mut stream, "404 Not Found", "Unknown path. Use /summary or /events."),
+  }
+}
+
+fn load_profiles(connection: &Connection, profile_filter: Option<&str>) -> Result<Vec<(String, String)>, String> {
+  let mut statement = connection
+    .prepare(
+      "SELECT id, display_name
+       FROM collection_data_profiles
+       WHERE ?1 IS NULL OR id = ?1
+       ORDER BY display_name COLLATE NOCASE",
+    )
+    .map_err(|e| e.to_string())?;
+  let rows = statement
+    .query_map(params![profile_filter], |row| Ok((row.get(0)?, row.get(1)?)))
+    .map_err(|e| e.to_string())?;
+  rows.collect::<Result<_, _>>().map_err(|e| e.to_string())
+}
+
+fn load_profile_summary(connection: &Connection, profile_id: &str, name: &str) -> Result<ProfileSummaryDto, String> {
+  let source_id = load_profile_price_preferences(connection, profile_id)?.price_source_id;
+  // Same cache key as get_collection_valuation with default arguments, so the two share entries.
+  let cache_key = format!("valuation:all:{}:listed", source_id);
+  let valuation = load_cached_valuation(connection, profile_id, &cache_key, |connection| {
+    load_collection_valuation(connection, profile_id, "all", &source_id, None)
+  })?;
+  Ok(ProfileSummaryDto {
+    profile_id: profile_id.to_string(),
+    name: name.to_string(),
+    currency: valuation.currency,
+    price_source_id: valuation.price_source_id,
+    copy_count: valuation.copy_count,
+    total_value: valuation.total_value,
+    total_value_formatted: valuation.total_value_formatted,
+  })
+}
+
+fn load_summary(connection: &Connection, profile_filter: Option<&str>) -> Result<FeedSummaryDto, String> {
+  let profiles = load_profiles(connection, profile_filter)?
+    .iter()
+    .map(|(profile_id, name)| load_profile_summary(connection, profile_id, name))
+    .collect::<Result<_, _>>()?;
+  Ok(FeedSummaryDto {
+    generated_at: now_iso(),
+    price_version: current_price_version(connection)?,
+    profiles,
+  })
+}
+
+fn load_mutation_counter(connection: &Connection, profile_id: &str) -> Result<i64, String> {
+  connection
+    .query_row(
+      "SELECT IFNULL(MAX(mutation_counter), 0) FROM collection_data_rollups WHERE collection_id = ?1",
+      params![profile_id],
+      |row| row.get(0),
+    )
+    .map_err(|e| e.to_string())
+}
+
+fn load_alert_events_since(
+  connection: &Connection,
+  profile_filter: Option<&str>,
+  since: &str,
+) -> Result<Vec<PriceAlertEventDto>, String> {
+  let mut statement = connection
+    .prepare(
+      "SELECT e.alert_id, e.collection_id, a.printing_id, IFNULL(c.name, a.printing_id), a.direction, a.is_foil,
+              e.price_cents, e.threshold_cents, e.fired_at
+       FROM collection_data_price_alert_events e
+       JOIN collection_data_price_alerts a ON a.id = e.alert_id
+       LEFT JOIN card_data_printings p ON p.id = a.printing_id
+       LEFT JOIN card_data_cards c ON c.id = p.card_id
+       WHERE e.fired_at > ?1
+         AND (?2 IS NULL OR e.collection_id = ?2)
+       ORDER BY e.fired_at, e.id",
+    )
+    .map_err(|e| e.to_string())?;
+  let rows = statement
+    .query_map(params![since, profile_filter], |row| {
+      Ok(PriceAlertEventDto {
+        alert_id: row.get(0)?,
+        profile_id: row.get(1)?,
+        scryfall_id: row.get(2)?,
+        name: row.get(3)?,
+        direction: row.get(4)?,
+        foil: row.get::<usize, i64>(5)? != 0,
+        price: cents_to_price(row.get(6)?),
+        threshold: cents_to_price(row.get(7)?),
+        fired_at: row.get(8)?,
+      })
+    })
+    .map_err(|e| e.to_string())?;
+  rows.collect::<Result<_, _>>().map_err(|e| e.to_string())
+}
+
+fn write_event(stream: &mut TcpStream, event: &str, data: &impl Serialize) -> Result<(), String> {
+  let data = serde_json::to_string(data).map_err(|e| e.to_string())?;
+  write!(stream, "event: {}\ndata: {}\n\n", event, data).map_err(|e| e.to_string())?;
+  stream.flush().map_err(|e| e.to_string())
+}
+
+// Sends a `collection` event per profile on connect, then polls: `collection` when a profile's
+// owned rows change, `prices` (followed by fresh `collection` events) when prices are synced and
+// `alert` for each price alert that fires. Ends when the client goes away or the feed stops.
+fn stream_events<R: Runtime>(
+  app: &AppHandle<R>,
+  mut stream: TcpStream,
+  profile_filter: Option<&str>,
+  stop: &AtomicBool,
+) -> Result<(), String> {
+  // Fail before the stream headers go out, while the client can still get a status code.
+  if open_database(&app.state::<AppState>().db_path()).is_err() {
+    return write_error(&mut stream, "500 Internal Server Error", DATABASE_UNAVAILABLE);
+  }
+  write!(
+    stream,
+    "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nAccess-Control-Allow-Origin: *\r\nConnection: keep-alive\r\n\r\n"
+  )
+  .map_err(|e| e.to_string())?;
+
+  let mut price_version: Option<String> = None;
+  let mut counters: Vec<(String, i64)> = Vec::new();
+  let mut alerts_since = now_iso();
+  let mut last_write = Instant::now();
+  while !stop.load(Ordering::SeqCst) {
+    // Reopened every round so a workspace switch takes effect on open streams.
+    let connection = open_database(&app.state::<AppState>().db_path())?;
+    let current_price_version = current_price_version(&connection)?;
+    let prices_changed = price_version.as_ref().is_some_and(|version| *version != current_price_version);
+    if prices_changed {
+      write_event(
+        &mut stream,
+        "prices",
+        &serde_json::json!({ "priceVersion": current_price_version, "generatedAt": now_iso() }),
+      )?;
+      last_write = Instant::now();
+    }
+    let first_round = price_version.is_none();
+    price_version = Some(current_price_version);
+
+    for (profile_id, name) in load_profiles(&connection, profile_filter)? {
+      let counter = load_mutation_counter(&connection, &profile_id)?;
+      let previous = counters.iter_mut().find(|(id, _)| *id == profile_id);
+      let changed = match previous {
+        Some((_, seen)) if *seen == counter => false,
+        Some((_, seen)) => {
+          *seen = counter;
+          true
+        }
+        None => {
+          counters.push((profile_id.clone(), counter));
+          true
+        }
+      };
+      if changed || prices_changed || first_round {
+        write_event(&mut stream, "collection", &load_profile_summary(&connection, &profile_id, &name)?)?;
+        last_write = Instant::now();
+      }
+    }
+
+    for alert in load_alert_events_since(&connection, profile_filter, &alerts_since)? {
+      alerts_since = alert.fired_at.clone();
+      write_event(&mut stream, "alert", &alert)?;
+      last_write = Instant::now();
+    }
+    drop(connection);
+
+    if last_write.elapsed() >= KEEPALIVE_INTERVAL {
+      stream.write_all(b": keepalive\n\n").map_err(|e| e.to_string())?;
+      stream.flush().map_err(|e| e.to_string())?;
+      last_write = Instant::now();
+    }
+    thread::sleep(POLL_INTERVAL);
+  }
+  Ok(())
+}
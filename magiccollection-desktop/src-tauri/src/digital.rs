@@ -0,0 +1,384 @@
+// Arena and MTGO collections: imports of tracker and client CSV exports into
+// collection_data_digital_items, and a card-by-card comparison with the paper collection.
+// Digital copies never count toward paper values or reports.
+use std::collections::HashMap;
+use std::path::Path;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+  find_deck_line_printing, normalized_csv_headers, now_iso, parse_csv_import_foil, parse_csv_import_quantity,
+  resolve_import_set_code, CsvImportSkippedRowDto, ImportedDeckLine, CSV_IMPORT_SKIPPED_SAMPLE_LIMIT,
+};
+
+pub(crate) const DIGITAL_PLATFORMS: [&str; 2] = ["arena", "mtgo"];
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DigitalImportSummaryDto {
+  platform: String,
+  pub(crate) rows_read: i64,
+  rows_imported: i64,
+  copies_imported: i64,
+  rows_skipped: i64,
+  // Distinct printings now held on the platform.
+  printing_count: i64,
+  // Capped at CSV_IMPORT_SKIPPED_SAMPLE_LIMIT; rows_skipped has the full count.
+  skipped_details: Vec<CsvImportSkippedRowDto>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DigitalItemDto {
+  platform: String,
+  scryfall_id: String,
+  name: String,
+  set_code: String,
+  collector_number: String,
+  quantity: i64,
+  foil_quantity: i64,
+  imported_at: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DigitalPaperCardDto {
+  card_id: String,
+  name: String,
+  paper_copies: i64,
+  arena_copies: i64,
+  mtgo_copies: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DigitalPaperReportDto {
+  profile_id: String,
+  // Platforms compared; both unless the report was asked for one.
+  platforms: Vec<String>,
+  // Cards by name across printings. Basic lands are left out.
+  in_both: Vec<DigitalPaperCardDto>,
+  digital_only: Vec<DigitalPaperCardDto>,
+  paper_only: Vec<DigitalPaperCardDto>,
+}
+
+pub(crate) fn normalize_digital_platform(value: &str) -> Result<String, String> {
+  let normalized = match value.trim().to_lowercase().as_str() {
+    "mtga" | "arena" => "arena".to_string(),
+    other => other.to_string(),
+  };
+  if DIGITAL_PLATFORMS.contains(&normalized.as_str()) {
+    Ok(normalized)
+  } else {
+    Err(format!(
+      "Unsupported digital platform '{}'. Use one of: {}.",
+      value.trim(),
+      DIGITAL_PLATFORMS.join(", ")
+    ))
+  }
+}
+
+// Header positions for MTGO's "Card Name, Quantity, ID #, Rarity, Set, Collector #, Premium"
+// export and the looser headers Arena trackers write.
+struct DigitalCsvColumns {
+  name: usize,
+  quantity: usize,
+  set_code: Option<usize>,
+  collector_number: Option<usize>,
+  mtgo_id: Option<usize>,
+  premium: Option<usize>,
+}
+
+impl DigitalCsvColumns {
+  fn from_headers(headers: &csv::StringRecord, platform: &str) -> Result<Self, String> {
+    let normalized = normalized_csv_headers(headers);
+    let first = |names: &[&str]| {
+      names
+        .iter()
+        .find_map(|name| normalized.iter().position(|header| header == name))
+    };
+    let (Some(name), Some(quantity)) = (
+      first(&["card name", "name", "card"]),
+      first(&["quantity", "count", "owned", "qty", "amount"]),
+    ) else {
+      return Err(format!(
+        "{} CSV is missing a Card Name or Quantity column.",
+        if platform == "mtgo" { "MTGO" } else { "Arena" }
+      ));
+    };
+    let is_mtgo = platform == "mtgo";
+    Ok(DigitalCsvColumns {
+      name,
+      quantity,
+      set_code: first(&["set", "set code", "edition", "expansion"]),
+      collector_number: first(&["collector #", "collector number", "number", "cn"]),
+      mtgo_id: first(&["id #", "id"]).filter(|_| is_mtgo),
+      premium: first(&["premium", "foil"]).filter(|_| is_mtgo),
+    })
+  }
+}
+
+// The printing and finish for one export row, or why it is skipped. MTGO catalog ids match first;
+// otherwise the set (through the set aliases), number and name pick the printing.
+fn resolve_digital_row(
+  connection: &Connection,
+  record: &csv::StringRecord,
+  columns: &DigitalCsvColumns,
+) -> Result<Result<(String, bool, i64), String>, String> {
+  let pick = |index: Option<usize>| index.and_then(|index| record.get(index)).unwrap_or("").trim();
+  let name = pick(Some(columns.name));
+  let quantity = parse_csv_import_quantity(pick(Some(columns.quantity)));
+  if quantity <= 0 {
+    return Ok(Err("Quantity missing or invalid".to_string()));
+  }
+  if name.is_empty() {
+    return Ok(Err("Card Name missing".to_string()));
+  }
+
+  if let Ok(mtgo_id) = pick(columns.mtgo_id).parse::<i64>() {
+    let matched: Option<(String, bool)> = connection
+      .query_row(
+        "SELECT id, IFNULL(mtgo_foil_id = ?1, 0)
+         FROM card_data_printings
+         WHERE mtgo_id = ?1 OR mtgo_foil_id = ?1
+         ORDER BY IFNULL(mtgo_id = ?1, 0) DESC
+         LIMIT 1",
+        params![mtgo_id],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+      )
+      .optional()
+      .map_err(|e| e.to_string())?;
+    if let Some((printing_id, foil)) = matched {
+      return Ok(Ok((printing_id, foil, quantity)));
+    }
+  }
+
+  let set_code = pick(columns.set_code);
+  let set_code = match resolve_import_set_code(connection, set_code)? {
+    Some(code) => Some(code),
+    None => Some(set_code.to_lowercase()).filter(|code| !code.is_empty()),
+  };
+  let line = ImportedDeckLine {
+    board: "main",
+    quantity,
+    name: name.to_string(),
+    scryfall_id: None,
+    set_code,
+    collector_number: Some(pick(columns.collector_number).to_string()).filter(|number| !number.is_empty()),
+  };
+  match find_deck_line_printing(connection, &line)? {
+    Some(printing_id) => Ok(Ok((printing_id, parse_csv_import_foil(pick(columns.premium)), quantity))),
+    None => Ok(Err(format!("No card named \"{}\" in the local catalog", name))),
+  }
+}
+
+// Replaces the profile's holdings on `platform` with the export at `path`. Rows for the same
+// printing and finish add up.
+pub(crate) fn import_digital_collection_file(
+  connection: &mut Connection,
+  profile_id: &str,
+  platform: &str,
+  path: &Path,
+) -> Result<DigitalImportSummaryDto, String> {
+  let mut reader = csv::ReaderBuilder::new()
+    .flexible(true)
+    .from_path(path)
+    .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+  let headers = reader
+    .headers()
+    .map_err(|e| format!("Failed to read CSV header from {}: {}", path.display(), e))?
+    .clone();
+  let columns = DigitalCsvColumns::from_headers(&headers, platform)?;
+
+  let mut summary = DigitalImportSummaryDto {
+    platform: platform.to_string(),
+    rows_read: 0,
+    rows_imported: 0,
+    copies_imported: 0,
+    rows_skipped: 0,
+    printing_count: 0,
+    skipped_details: Vec::new(),
+  };
+  let skip = |summary: &mut DigitalImportSummaryDto, row_number: i64, reason: String, record: &csv::StringRecord| {
+    summary.rows_skipped += 1;
+    if summary.skipped_details.len() < CSV_IMPORT_SKIPPED_SAMPLE_LIMIT {
+      summary.skipped_details.push(CsvImportSkippedRowDto {
+        row_number,
+        reason,
+        preview: record.iter().take(6).collect::<Vec<_>>().join(" | "),
+      });
+    }
+  };
+
+  // printing id -> (non-foil, foil) copies, in file order.
+  let mut holdings: Vec<(String, i64, i64)> = Vec::new();
+  let mut positions: HashMap<String, usize> = HashMap::new();
+  let mut record = csv::StringRecord::new();
+  loop {
+    let row_number = reader.position().line() as i64;
+    match reader.read_record(&mut record) {
+      Ok(false) => break,
+      Ok(true) => {}
+      Err(error) => {
+        summary.rows_read += 1;
+        skip(&mut summary, row_number, format!("Unreadable row: {}", error), &csv::StringRecord::new());
+        continue;
+      }
+    }
+    if record.iter().all(|cell| cell.trim().is_empty()) {
+      continue;
+    }
+    summary.rows_read += 1;
+    match resolve_digital_row(connection, &record, &columns)? {
+      Ok((printing_id, foil, quantity)) => {
+        let index = *positions.entry(printing_id.clone()).or_insert_with(|| {
+          holdings.push((printing_id, 0, 0));
+          holdings.len() - 1
+        });
+        if foil {
+          holdings[index].2 += quantity;
+        } else {
+          holdings[index].1 += quantity;
+        }
+        summary.rows_imported += 1;
+        summary.copies_imported += quantity;
+      }
+      Err(reason) => skip(&mut summary, row_number, reason, &record),
+    }
+  }
+
+  let now = now_iso();
+  let tx = connection.transaction().map_err(|e| e.to_string())?;
+  tx.execute(
+    "DELETE FROM collection_data_digital_items WHERE collection_id = ?1 AND platform = ?2",
+    params![profile_id, platform],
+  )
+  .map_err(|e| e.to_string())?;
+  for (printing_id, quantity, foil_quantity) in &holdings {
+    tx.execute(
+      "INSERT INTO collection_data_digital_items (
+         id, collection_id, platform, printing_id, quantity_nonfoil, quantity_foil, imported_at
+       ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+      params![
+        Uuid::new_v4().to_string(),
+        profile_id,
+        platform,
+        printing_id,
+        quantity,
+        foil_quantity,
+        &now
+      ],
+    )
+    .map_err(|e| e.to_string())?;
+  }
+  tx.commit().map_err(|e| e.to_string())?;
+  summary.printing_count = holdings.len() as i64;
+  Ok(summary)
+}
+
+pub(crate) fn load_digital_collection(
+  connection: &Connection,
+  profile_id: &str,
+  platform: Option<&str>,
+) -> Result<Vec<DigitalItemDto>, String> {
+  let mut statement = connection
+    .prepare(
+      "SELECT di.platform, p.id, c.name, p.set_code, p.collector_number, di.quantity_nonfoil,
+              di.quantity_foil, di.imported_at
+       FROM collection_data_digital_items di
+       JOIN card_data_printings p ON p.id = di.printing_id
+       JOIN card_data_cards c ON c.id = p.card_id
+       WHERE di.collection_id = ?1
+         AND (?2 IS NULL OR di.platform = ?2)
+       ORDER BY di.platform, c.name COLLATE NOCASE, p.set_code, p.collector_number",
+    )
+    .map_err(|e| e.to_string())?;
+  let rows = statement
+    .query_map(params![profile_id, platform], |row| {
+      Ok(DigitalItemDto {
+        platform: row.get(0)?,
+        scryfall_id: row.get(1)?,
+        name: row.get(2)?,
+        set_code: row.get(3)?,
+        collector_number: row.get(4)?,
+        quantity: row.get(5)?,
+        foil_quantity: row.get(6)?,
+        imported_at: row.get(7)?,
+      })
+    })
+    .map_err(|e| e.to_string())?;
+  let mut items = Vec::new();
+  for row in rows {
+    items.push(row.map_err(|e| e.to_string())?);
+  }
+  Ok(items)
+}
+
+// Paper copies count paper printings only; digital copies count the platforms compared. Cards
+// match by name (or oracle id), since paper and digital printings can have separate card rows.
+pub(crate) fn load_digital_paper_report(
+  connection: &Connection,
+  profile_id: &str,
+  platform: Option<&str>,
+) -> Result<DigitalPaperReportDto, String> {
+  let mut statement = connection
+    .prepare(
+      "WITH holdings AS (
+         SELECT p.card_id, SUM(ci.quantity_nonfoil + ci.quantity_foil) AS paper, 0 AS arena, 0 AS mtgo
+         FROM collection_data_collection_items ci
+         JOIN card_data_printings p ON p.id = ci.printing_id
+         WHERE ci.collection_id = ?1 AND p.is_digital = 0
+         GROUP BY p.card_id
+         UNION ALL
+         SELECT p.card_id, 0,
+                SUM(CASE WHEN di.platform = 'arena' THEN di.quantity_nonfoil + di.quantity_foil ELSE 0 END),
+                SUM(CASE WHEN di.platform = 'mtgo' THEN di.quantity_nonfoil + di.quantity_foil ELSE 0 END)
+         FROM collection_data_digital_items di
+         JOIN card_data_printings p ON p.id = di.printing_id
+         WHERE di.collection_id = ?1 AND (?2 IS NULL OR di.platform = ?2)
+         GROUP BY p.card_id
+       )
+       SELECT MIN(c.id), MIN(c.name), SUM(h.paper), SUM(h.arena), SUM(h.mtgo)
+       FROM holdings h
+       JOIN card_data_cards c ON c.id = h.card_id
+       WHERE IFNULL(c.type_line, '') NOT LIKE 'Basic Land%'
+       GROUP BY COALESCE(c.name_normalized, c.oracle_id, c.id)
+       HAVING SUM(h.paper) > 0 OR SUM(h.arena) + SUM(h.mtgo) > 0
+       ORDER BY 2 COLLATE NOCASE, 1",
+    )
+    .map_err(|e| e.to_string())?;
+  let rows = statement
+    .query_map(params![profile_id, platform], |row| {
+      Ok(DigitalPaperCardDto {
+        card_id: row.get(0)?,
+        name: row.get(1)?,
+        paper_copies: row.get(2)?,
+        arena_copies: row.get(3)?,
+        mtgo_copies: row.get(4)?,
+      })
+    })
+    .map_err(|e| e.to_string())?;
+
+  let mut report = DigitalPaperReportDto {
+    profile_id: profile_id.to_string(),
+    platforms: match platform {
+      Some(platform) => vec![platform.to_string()],
+      None => DIGITAL_PLATFORMS.iter().map(|platform| platform.to_string()).collect(),
+    },
+    in_both: Vec::new(),
+    digital_only: Vec::new(),
+    paper_only: Vec::new(),
+  };
+  for row in rows {
+    let card = row.map_err(|e| e.to_string())?;
+    let digital_copies = card.arena_copies + card.mtgo_copies;
+    match (card.paper_copies > 0, digital_copies > 0) {
+      (true, true) => report.in_both.push(card),
+      (false, true) => report.digital_only.push(card),
+      _ => report.paper_only.push(card),
+    }
+  }
+  Ok(report)
+}
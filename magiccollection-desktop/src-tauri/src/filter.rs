@@ -0,0 +1,405 @@
+// Collection search queries such as `set:neo c:uw mv>=4 tag:trade sort:price`: the syntax of the
+// collection page search box (parseSearchPlan), parsed into a FilterQuery and compiled to a SQL
+// predicate over the owned row `ci`, its printing `p` and card `c`. Terms of different kinds must
+// all match; repeated set:, rarity:, lang: and cond: terms match any of their values.
+use rusqlite::types::Value;
+use std::collections::HashMap;
+
+use crate::{fold_card_name, AUTO_TAGS};
+
+const PRIMARY_TYPES: [&str; 9] = [
+  "artifact",
+  "battle",
+  "creature",
+  "enchantment",
+  "instant",
+  "land",
+  "planeswalker",
+  "sorcery",
+  "tribal",
+];
+const COLOR_SYMBOLS: [char; 5] = ['W', 'U', 'B', 'R', 'G'];
+
+#[derive(Default)]
+pub(crate) struct FilterQuery {
+  pub(crate) free_text: Vec<String>,
+  // Free-text term -> folded card name it is an alias of; filled by the caller before compiling.
+  pub(crate) name_aliases: HashMap<String, String>,
+  pub(crate) set_codes: Vec<String>,
+  pub(crate) type_terms: Vec<String>,
+  pub(crate) tags: Vec<String>,
+  pub(crate) color_contains: Vec<String>,
+  pub(crate) color_exact: Option<String>,
+  pub(crate) rarities: Vec<String>,
+  pub(crate) languages: Vec<String>,
+  pub(crate) conditions: Vec<String>,
+  pub(crate) foil_only: Option<bool>,
+  // is:trade / is:keep
+  pub(crate) for_trade: Option<bool>,
+  pub(crate) playset: bool,
+  // is:digital / not:digital
+  pub(crate) digital: Option<bool>,
+  pub(crate) mana_comparators: Vec<(String, f64)>,
+  // Last sort: term; not part of the predicate.
+  pub(crate) sort: Option<FilterSort>,
+}
+
+// `sort:price` or `sort:price-desc`. The key is passed through as typed; callers validate it.
+pub(crate) struct FilterSort {
+  pub(crate) key: String,
+  pub(crate) direction: Option<String>,
+}
+
+// A predicate with numbered parameters starting at the `first_param` given to compile.
+pub(crate) struct CompiledFilter {
+  pub(crate) sql: String,
+  pub(crate) params: Vec<Value>,
+}
+
+fn tokenize(query: &str) -> Vec<String> {
+  let mut terms = Vec::new();
+  let mut current = String::new();
+  let mut quoted = false;
+  for ch in query.chars() {
+    match ch {
+      '"' => quoted = !quoted,
+      ch if ch.is_whitespace() && !quoted => {
+        if !current.trim().is_empty() {
+          terms.push(current.trim().to_string());
+        }
+        current.clear();
+      }
+      ch => current.push(ch),
+    }
+  }
+  if !current.trim().is_empty() {
+    terms.push(current.trim().to_string());
+  }
+  terms
+}
+
+fn color_symbols(value: &str) -> String {
+  value
+    .to_uppercase()
+    .chars()
+    .filter(|ch| "WUBRGC".contains(*ch))
+    .collect()
+}
+
+fn parse_mana_comparator(term: &str) -> Option<(String, f64)> {
+  let rest = term.strip_prefix("mv")?;
+  for op in ["<=", ">=", "=", ":", "<", ">"] {
+    if let Some(value) = rest.strip_prefix(op) {
+      let parsed = value.parse::<f64>().ok().filter(|value| value.is_finite())?;
+      let op = if op == ":" { "=" } else { op };
+      return Some((op.to_string(), parsed));
+    }
+  }
+  None
+}
+
+fn parse_sort(value: &str) -> Option<FilterSort> {
+  let value = value.trim();
+  if value.is_empty() {
+    return None;
+  }
+  let (key, direction) = match value.rsplit_once('-') {
+    Some((key, direction)) if direction == "asc" || direction == "desc" => (key, Some(direction.to_string())),
+    _ => (value, None),
+  };
+  let key = match key {
+    "qty" => "quantity",
+    key => key,
+  };
+  Some(FilterSort {
+    key: key.to_string(),
+    direction,
+  })
+}
+
+pub(crate) fn parse(query: &str) -> FilterQuery {
+  let mut plan = FilterQuery::default();
+  for raw_term in tokenize(query) {
+    let term = raw_term.trim().to_lowercase();
+    if term.is_empty() {
+      continue;
+    }
+    if let Some(value) = term.strip_prefix("set:") {
+      if !value.trim().is_empty() {
+        plan.set_codes.push(value.trim().to_string());
+      }
+    } else if let Some(value) = term.strip_prefix("type:").or_else(|| term.strip_prefix("t:")) {
+      if !value.trim().is_empty() {
+        plan.type_terms.push(value.trim().to_string());
+      }
+    } else if let Some(value) = term.strip_prefix("tag:") {
+      if !value.trim().is_empty() {
+        plan.tags.push(value.trim().to_string());
+      }
+    } else if let Some(value) = term.strip_prefix("c:") {
+      let symbols = color_symbols(value);
+      if !symbols.is_empty() {
+        plan.color_contains.push(symbols);
+      }
+    } else if let Some(value) = term.strip_prefix("id:") {
+      let symbols = color_symbols(value);
+      if !symbols.is_empty() {
+        plan.color_exact = Some(symbols);
+      }
+    } else if let Some(value) = term.strip_prefix("rarity:") {
+      if !value.trim().is_empty() {
+        plan.rarities.push(value.trim().to_string());
+      }
+    } else if let Some(value) = term.strip_prefix("lang:") {
+      if !value.trim().is_empty() {
+        plan.languages.push(value.trim().to_string());
+      }
+    } else if let Some(value) = term.strip_prefix("cond:") {
+      if !value.trim().is_empty() {
+        plan.conditions.push(value.trim().to_uppercase());
+      }
+    } else if let Some(value) = term.strip_prefix("sort:") {
+      if let Some(sort) = parse_sort(value) {
+        plan.sort = Some(sort);
+      }
+    } else if term == "is:foil" {
+      plan.foil_only = Some(true);
+    } else if term == "is:nonfoil" {
+      plan.foil_only = Some(false);
+    } else if term == "is:trade" {
+      plan.for_trade = Some(true);
+    } else if term == "is:keep" {
+      plan.for_trade = Some(false);
+    } else if term == "is:playset" {
+      plan.playset = true;
+    } else if term == "is:digital" {
+      plan.digital = Some(true);
+    } else if term == "not:digital" {
+      plan.digital = Some(false);
+    } else if let Some(comparator) = parse_mana_comparator(&term) {
+      plan.mana_comparators.push(comparator);
+    } else {
+      let name_term = fold_card_name(term.strip_prefix("name:").unwrap_or(&term));
+      if !name_term.is_empty() {
+        plan.free_text.push(name_term);
+      }
+    }
+  }
+  plan
+}
+
+fn has_color_sql(symbol: char) -> String {
+  format!("instr(upper(IFNULL(c.color_identity_json, '')), '\"{}\"') > 0", symbol)
+}
+
+// Lowercased WUBRG symbols of the card's identity in that order, or `c` when it has none.
+fn identity_sql() -> String {
+  let any = COLOR_SYMBOLS
+    .iter()
+    .map(|symbol| has_color_sql(*symbol))
+    .collect::<Vec<_>>()
+    .join(" OR ");
+  let symbols = COLOR_SYMBOLS
+    .iter()
+    .map(|symbol| {
+      format!(
+        "CASE WHEN {} THEN '{}' ELSE '' END",
+        has_color_sql(*symbol),
+        symbol.to_ascii_lowercase()
+      )
+    })
+    .collect::<Vec<_>>()
+    .join(" || ");
+  format!("CASE WHEN {} THEN {} ELSE 'c' END", any, symbols)
+}
+
+// The first known card type before the em dash of the type line, else its first word, else
+// `unknown`; the same as extract_primary_type.
+fn primary_type_sql() -> String {
+  let type_line = "trim(IFNULL(c.type_line, ''))";
+  let left = format!(
+    "lower(trim(substr({tl}, 1, instr({tl} || '—', '—') - 1)))",
+    tl = type_line
+  );
+  let known = PRIMARY_TYPES
+    .iter()
+    .map(|name| format!("WHEN instr({}, '{}') > 0 THEN '{}'", left, name, name))
+    .collect::<Vec<_>>()
+    .join(" ");
+  format!(
+    "CASE WHEN {left} = '' THEN 'unknown' {known} ELSE substr({left}, 1, instr({left} || ' ', ' ') - 1) END",
+    left = left,
+    known = known
+  )
+}
+
+fn user_tags_sql() -> String {
+  let auto_tags = AUTO_TAGS
+    .iter()
+    .map(|tag| format!("'{}'", tag))
+    .collect::<Vec<_>>()
+    .join(", ");
+  format!(
+    "FROM collection_data_collection_item_tags oit
+     JOIN collection_data_tags t ON t.id = oit.tag_id
+     WHERE oit.collection_item_id = ci.id
+       AND lower(trim(t.name)) NOT IN ({})",
+    auto_tags
+  )
+}
+
+struct SqlBuilder {
+  clauses: Vec<String>,
+  params: Vec<Value>,
+  next_param: usize,
+}
+
+impl SqlBuilder {
+  fn bind(&mut self, value: Value) -> String {
+    let placeholder = format!("?{}", self.next_param);
+    self.next_param += 1;
+    self.params.push(value);
+    placeholder
+  }
+
+  fn bind_text(&mut self, value: &str) -> String {
+    self.bind(Value::Text(value.to_string()))
+  }
+
+  fn bind_list(&mut self, values: &[String]) -> String {
+    values
+      .iter()
+      .map(|value| self.bind_text(value))
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+}
+
+impl FilterQuery {
+  // Compiles every term except sort: into one predicate; an empty query compiles to `1 = 1`.
+  pub(crate) fn compile(&self, first_param: usize) -> CompiledFilter {
+    let mut builder = SqlBuilder {
+      clauses: Vec::new(),
+      params: Vec::new(),
+      next_param: first_param,
+    };
+    let user_tags = user_tags_sql();
+    let primary_type = primary_type_sql();
+    let user_tag_contains =
+      |placeholder: &str| format!("EXISTS (SELECT 1 {} AND instr(lower(trim(t.name)), {}) > 0)", user_tags, placeholder);
+
+    if !self.free_text.is_empty() {
+      let name = "IFNULL(c.name_normalized, lower(c.name))";
+      let searchable = format!(
+        "({name} || ' ' || lower(p.set_code) || ' ' || lower(p.collector_number) || ' ' || {primary_type}
+          || ' ' || {identity} || ' ' || IFNULL((SELECT group_concat(lower(trim(t.name)), ' ') {user_tags}), ''))",
+        name = name,
+        primary_type = primary_type,
+        identity = identity_sql(),
+        user_tags = user_tags,
+      );
+      for term in &self.free_text {
+        let placeholder = builder.bind_text(term);
+        let clause = match self.name_aliases.get(term) {
+          Some(alias) => {
+            let alias_placeholder = builder.bind_text(alias);
+            format!(
+              "(instr({}, {}) > 0 OR {} = {})",
+              searchable, placeholder, name, alias_placeholder
+            )
+          }
+          None => format!("instr({}, {}) > 0", searchable, placeholder),
+        };
+        builder.clauses.push(clause);
+      }
+    }
+    if !self.set_codes.is_empty() {
+      let list = builder.bind_list(&self.set_codes);
+      builder.clauses.push(format!("lower(p.set_code) IN ({})", list));
+    }
+    for term in &self.type_terms {
+      let placeholder = builder.bind_text(term);
+      builder.clauses.push(format!(
+        "(instr({primary_type}, {value}) > 0
+          OR instr(lower(trim(IFNULL(c.type_line, ''))), {value}) > 0
+          OR {tag})",
+        primary_type = primary_type,
+        value = placeholder,
+        tag = user_tag_contains(&placeholder),
+      ));
+    }
+    for tag in &self.tags {
+      let placeholder = builder.bind_text(tag);
+      builder.clauses.push(user_tag_contains(&placeholder));
+    }
+    for target in &self.color_contains {
+      if target.contains('C') {
+        builder.clauses.push(format!("({}) = 'c'", identity_sql()));
+      }
+      for symbol in target.chars().filter(|ch| *ch != 'C') {
+        builder.clauses.push(has_color_sql(symbol));
+      }
+    }
+    if let Some(exact) = &self.color_exact {
+      if exact == "C" {
+        builder.clauses.push(format!("({}) = 'c'", identity_sql()));
+      } else {
+        for symbol in COLOR_SYMBOLS {
+          let wanted = if exact.contains(symbol) { "" } else { "NOT " };
+          builder.clauses.push(format!("{}({})", wanted, has_color_sql(symbol)));
+        }
+      }
+    }
+    if !self.rarities.is_empty() {
+      let list = builder.bind_list(&self.rarities);
+      builder.clauses.push(format!("lower(IFNULL(p.rarity, '')) IN ({})", list));
+    }
+    if !self.languages.is_empty() {
+      let list = builder.bind_list(&self.languages);
+      builder.clauses.push(format!("lower(ci.language) IN ({})", list));
+    }
+    if !self.conditions.is_empty() {
+      let list = builder.bind_list(&self.conditions);
+      builder.clauses.push(format!("upper(ci.condition_code) IN ({})", list));
+    }
+    match self.foil_only {
+      Some(true) => builder.clauses.push("ci.quantity_foil > 0".to_string()),
+      Some(false) => builder.clauses.push("ci.quantity_nonfoil > 0".to_string()),
+      None => {}
+    }
+    match self.for_trade {
+      Some(true) => builder.clauses.push("IFNULL(ci.trade_status, '') = 'trade'".to_string()),
+      Some(false) => builder.clauses.push("IFNULL(ci.trade_status, '') <> 'trade'".to_string()),
+      None => {}
+    }
+    if self.playset {
+      builder
+        .clauses
+        .push("ci.quantity_nonfoil + ci.quantity_foil >= 4".to_string());
+    }
+    match self.digital {
+      Some(true) => builder.clauses.push("IFNULL(p.is_digital, 0) = 1".to_string()),
+      Some(false) => builder.clauses.push("IFNULL(p.is_digital, 0) = 0".to_string()),
+      None => {}
+    }
+    for (op, value) in &self.mana_comparators {
+      let op = match op.as_str() {
+        "<" | "<=" | ">" | ">=" => op.as_str(),
+        _ => "=",
+      };
+      let placeholder = builder.bind(Value::Real(*value));
+      builder
+        .clauses
+        .push(format!("(c.cmc IS NOT NULL AND c.cmc {} {})", op, placeholder));
+    }
+
+    CompiledFilter {
+      sql: if builder.clauses.is_empty() {
+        "1 = 1".to_string()
+      } else {
+        builder.clauses.join("\n AND ")
+      },
+      params: builder.params,
+    }
+  }
+}
@@ -0,0 +1,260 @@
+// Portfolio gain/loss: the cost basis of held copies against their TCGplayer Market and CK Buylist
+// value (unrealized), and sale proceeds against the cost captured on each sale line (realized).
+// Amounts are in the profile's valuation currency. Costs convert to USD at the purchase-date
+// rate, then into the valuation currency like the USD channel prices.
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  build_finish_price_trend_by_column, cents_to_price, format_money, fx_rate_on, load_profile_price_preferences,
+  price_to_cents, purchase_rate_date, CONDITION_NM_ID, FINISH_FOIL_ID, FINISH_NONFOIL_ID, PRICE_CHANNEL_CURRENCY,
+};
+
+const MARKET_COLUMN: &str = "tcg_market_cents";
+const BUYLIST_COLUMN: &str = "ck_buylist_cents";
+// Held lines listed on each side of the winners and losers.
+const TOP_MOVER_COUNT: usize = 10;
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PortfolioReportDto {
+  profile_id: String,
+  currency: String,
+  // Unrealized totals cover held lines with both a converted cost and a TCGplayer Market price.
+  cost_basis: f64,
+  cost_basis_formatted: String,
+  market_value: f64,
+  market_value_formatted: String,
+  unrealized_gain: f64,
+  unrealized_gain_formatted: String,
+  // What the same lines would fetch at CK Buylist; lines without a buylist price count as zero.
+  buylist_value: f64,
+  buylist_value_formatted: String,
+  buylist_gain: f64,
+  buylist_gain_formatted: String,
+  held_line_count: i64,
+  // Held lines left out of the totals: no rate for the purchase currency and date, or no price.
+  missing_rate_count: i64,
+  missing_price_count: i64,
+  // Realized totals cover sale lines whose cost was known when they were sold.
+  proceeds: f64,
+  proceeds_formatted: String,
+  realized_cost_basis: f64,
+  realized_cost_basis_formatted: String,
+  realized_gain: f64,
+  realized_gain_formatted: String,
+  copies_sold: i64,
+  missing_sale_cost_count: i64,
+  total_gain: f64,
+  total_gain_formatted: String,
+  // Biggest unrealized gains first, and biggest unrealized losses first.
+  top_winners: Vec<PortfolioLineDto>,
+  top_losers: Vec<PortfolioLineDto>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PortfolioLineDto {
+  owned_item_id: String,
+  scryfall_id: String,
+  name: String,
+  set_code: String,
+  collector_number: String,
+  copies: i64,
+  cost_basis: f64,
+  market_value: f64,
+  buylist_value: Option<f64>,
+  gain: f64,
+  gain_formatted: String,
+  // Gain over cost basis; None for copies bought for nothing.
+  gain_percent: Option<f64>,
+}
+
+// Value of a row's copies in cents from the latest price in `column`, or None when a finish it
+// holds has no price.
+fn held_value_cents(
+  connection: &Connection,
+  scryfall_id: &str,
+  column: &str,
+  condition_id: i64,
+  quantity: i64,
+  foil_quantity: i64,
+) -> Result<Option<i64>, String> {
+  let mut total = 0_i64;
+  for (finish_id, finish_copies) in [(FINISH_NONFOIL_ID, quantity), (FINISH_FOIL_ID, foil_quantity)] {
+    if finish_copies <= 0 {
+      continue;
+    }
+    match build_finish_price_trend_by_column(connection, scryfall_id, column, condition_id, finish_id)?.current_price {
+      Some(price) => total += price_to_cents(price) * finish_copies,
+      None => return Ok(None),
+    }
+  }
+  Ok(Some(total))
+}
+
+pub(crate) fn load_portfolio_report(connection: &Connection, profile_id: &str) -> Result<PortfolioReportDto, String> {
+  let preferences = load_profile_price_preferences(connection, profile_id)?;
+  let money =
+    |cents: i64| format_money(cents_to_price(cents), preferences.amount_currency(), &preferences.money_locale);
+
+  let mut statement = connection
+    .prepare(
+      "SELECT ci.id, p.id, c.name, p.set_code, p.collector_number, IFNULL(cc.id, ?2),
+              ci.quantity_nonfoil, ci.quantity_foil, ci.purchase_price_cents,
+              IFNULL(ci.purchase_currency, ?3), ci.acquired_at, ci.created_at
+       FROM collection_data_collection_items ci
+       JOIN card_data_printings p ON p.id = ci.printing_id
+       JOIN card_data_cards c ON c.id = p.card_id
+       LEFT JOIN card_data_condition_codes cc ON cc.condition_code = ci.condition_code
+       WHERE ci.collection_id = ?1
+         AND (ci.quantity_nonfoil > 0 OR ci.quantity_foil > 0)
+         AND ci.purchase_price_cents IS NOT NULL
+       ORDER BY c.name COLLATE NOCASE, p.set_code, p.collector_number, ci.id",
+    )
+    .map_err(|e| e.to_string())?;
+  let rows = statement
+    .query_map(params![profile_id, CONDITION_NM_ID, PRICE_CHANNEL_CURRENCY], |row| {
+      Ok((
+        row.get::<usize, String>(0)?,
+        row.get::<usize, String>(1)?,
+        row.get::<usize, String>(2)?,
+        row.get::<usize, String>(3)?,
+        row.get::<usize, String>(4)?,
+        row.get::<usize, i64>(5)?,
+        row.get::<usize, i64>(6)?,
+        row.get::<usize, i64>(7)?,
+        row.get::<usize, i64>(8)?,
+        row.get::<usize, String>(9)?,
+        row.get::<usize, Option<String>>(10)?,
+        row.get::<usize, String>(11)?,
+      ))
+    })
+    .map_err(|e| e.to_string())?;
+
+  // (line, gain in cents) for every held line that made the totals.
+  let mut movers = Vec::new();
+  let (mut cost_cents, mut market_cents, mut buylist_cents) = (0_i64, 0_i64, 0_i64);
+  let (mut held_line_count, mut missing_rate_count, mut missing_price_count) = (0_i64, 0_i64, 0_i64);
+  for row in rows {
+    let (
+      owned_item_id,
+      scryfall_id,
+      name,
+      set_code,
+      collector_number,
+      condition_id,
+      quantity,
+      foil_quantity,
+      purchase_price_cents,
+      purchase_currency,
+      acquired_at,
+      created_at,
+    ) = row.map_err(|e| e.to_string())?;
+    held_line_count += 1;
+    let copies = quantity + foil_quantity;
+    let purchase_date = purchase_rate_date(acquired_at.as_deref(), &created_at);
+    let Some((units_per_usd, _)) = fx_rate_on(connection, &purchase_currency, &purchase_date)? else {
+      missing_rate_count += 1;
+      continue;
+    };
+    let Some(line_market_cents) =
+      held_value_cents(connection, &scryfall_id, MARKET_COLUMN, condition_id, quantity, foil_quantity)?
+    else {
+      missing_price_count += 1;
+      continue;
+    };
+    let unit_cost_cents = (purchase_price_cents as f64 / units_per_usd).round() as i64;
+    let line_cost_cents = preferences.convert_cents(unit_cost_cents) * copies;
+    let line_market_cents = preferences.convert_cents(line_market_cents);
+    let line_buylist_cents =
+      held_value_cents(connection, &scryfall_id, BUYLIST_COLUMN, condition_id, quantity, foil_quantity)?
+        .map(|cents| preferences.convert_cents(cents));
+    cost_cents += line_cost_cents;
+    market_cents += line_market_cents;
+    buylist_cents += line_buylist_cents.unwrap_or(0);
+
+    let gain_cents = line_market_cents - line_cost_cents;
+    movers.push((
+      PortfolioLineDto {
+        owned_item_id,
+        scryfall_id,
+        name,
+        set_code,
+        collector_number,
+        copies,
+        cost_basis: cents_to_price(line_cost_cents),
+        market_value: cents_to_price(line_market_cents),
+        buylist_value: line_buylist_cents.map(cents_to_price),
+        gain: cents_to_price(gain_cents),
+        gain_formatted: money(gain_cents),
+        gain_percent: (line_cost_cents > 0)
+          .then(|| ((gain_cents as f64 / line_cost_cents as f64) * 10_000.0).round() / 100.0),
+      },
+      gain_cents,
+    ));
+  }
+
+  // Sales are recorded in the valuation currency, like the cost captured on their lines; sales
+  // made under an earlier valuation currency are left out.
+  let (proceeds_cents, sold_cost_cents, copies_sold, missing_sale_cost_count): (i64, i64, i64, i64) = connection
+    .query_row(
+      "SELECT IFNULL(SUM(CASE WHEN sl.unit_cost_cents IS NOT NULL THEN sl.line_total_cents END), 0),
+              IFNULL(SUM(sl.unit_cost_cents * (sl.quantity_nonfoil + sl.quantity_foil)), 0),
+              IFNULL(SUM(sl.quantity_nonfoil + sl.quantity_foil), 0),
+              COUNT(*) - COUNT(sl.unit_cost_cents)
+       FROM collection_data_sale_lines sl
+       JOIN collection_data_sales s ON s.id = sl.sale_id
+       WHERE s.collection_id = ?1
+         AND s.currency = ?2",
+      params![profile_id, preferences.amount_currency()],
+      |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+    )
+    .map_err(|e| e.to_string())?;
+
+  let mut top_winners = movers
+    .iter()
+    .filter(|(_, gain_cents)| *gain_cents > 0)
+    .cloned()
+    .collect::<Vec<_>>();
+  top_winners.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.name.cmp(&b.0.name)));
+  let mut top_losers = movers
+    .into_iter()
+    .filter(|(_, gain_cents)| *gain_cents < 0)
+    .collect::<Vec<_>>();
+  top_losers.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.name.cmp(&b.0.name)));
+
+  let unrealized_gain_cents = market_cents - cost_cents;
+  let buylist_gain_cents = buylist_cents - cost_cents;
+  let realized_gain_cents = proceeds_cents - sold_cost_cents;
+  let total_gain_cents = unrealized_gain_cents + realized_gain_cents;
+  Ok(PortfolioReportDto {
+    profile_id: profile_id.to_string(),
+    currency: preferences.amount_currency().to_string(),
+    cost_basis: cents_to_price(cost_cents),
+    cost_basis_formatted: money(cost_cents),
+    market_value: cents_to_price(market_cents),
+    market_value_formatted: money(market_cents),
+    unrealized_gain: cents_to_price(unrealized_gain_cents),
+    unrealized_gain_formatted: money(unrealized_gain_cents),
+    buylist_value: cents_to_price(buylist_cents),
+    buylist_value_formatted: money(buylist_cents),
+    buylist_gain: cents_to_price(buylist_gain_cents),
+    buylist_gain_formatted: money(buylist_gain_cents),
+    held_line_count,
+    missing_rate_count,
+    missing_price_count,
+    proceeds: cents_to_price(proceeds_cents),
+    proceeds_formatted: money(proceeds_cents),
+    realized_cost_basis: cents_to_price(sold_cost_cents),
+    realized_cost_basis_formatted: money(sold_cost_cents),
+    realized_gain: cents_to_price(realized_gain_cents),
+    realized_gain_formatted: money(realized_gain_cents),
+    copies_sold,
+    missing_sale_cost_count,
+    total_gain: cents_to_price(total_gain_cents),
+    total_gain_formatted: money(total_gain_cents),
+    top_winners: top_winners.into_iter().take(TOP_MOVER_COUNT).map(|(line, _)| line).collect(),
+    top_losers: top_losers.into_iter().take(TOP_MOVER_COUNT).map(|(line, _)| line).collect(),
+  })
+}
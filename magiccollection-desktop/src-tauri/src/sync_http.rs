@@ -0,0 +1,438 @@
+// Transport for the catalog, price and combo syncs. Fetchers describe each request with a
+// SyncHttpRequest and read the body from the active SyncHttp: the network by default, recorded
+// payloads under --offline-fixture, or a test's fixtures. Live bodies can also be archived to disk
+// as they are read.
+use chrono::{NaiveDateTime, Utc};
+use reqwest::blocking::Client;
+use reqwest::header::{HeaderName, ACCEPT, USER_AGENT};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufReader, Cursor, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+pub(crate) const APP_USER_AGENT: &str = "MagicCollectionDesktop/1.0 (+https://github.com/joemoffett1/Space-Dog)";
+pub(crate) const OFFLINE_FIXTURE_ARG: &str = "--offline-fixture";
+pub(crate) const PAYLOAD_ARCHIVE_DIR: &str = "vendor-payloads";
+const PAYLOAD_ARCHIVE_SUFFIX: &str = ".json.zst";
+// Bodies the caller stopped reading early; only the bytes it read are in the copy.
+const PAYLOAD_ARCHIVE_TRUNCATED_SUFFIX: &str = ".truncated.json.zst";
+const PAYLOAD_ARCHIVE_STAMP_FORMAT: &str = "%Y%m%dT%H%M%S%.3fZ";
+
+// Sample payloads for demos, stored under the names fixture_file_name gives their URLs so the
+// folder doubles as an example for --offline-fixture=<dir>.
+const BUNDLED_FIXTURES: [(&str, &str); 10] = [
+  (
+    "https://api.scryfall.com/bulk-data",
+    include_str!("../fixtures/offline/api.scryfall.com_bulk-data.json"),
+  ),
+  (
+    "https://data.scryfall.io/default-cards/offline-fixture-sample.json",
+    include_str!("../fixtures/offline/data.scryfall.io_default-cards_offline-fixture-sample.json"),
+  ),
+  (
+    "https://api.scryfall.com/cards/collection",
+    include_str!("../fixtures/offline/api.scryfall.com_cards_collection.json"),
+  ),
+  (
+    "https://api.scryfall.com/sets",
+    include_str!("../fixtures/offline/api.scryfall.com_sets.json"),
+  ),
+  (
+    "https://tcgtracking.com/tcgapi/v1/1/sets",
+    include_str!("../fixtures/offline/tcgtracking.com_tcgapi_v1_1_sets.json"),
+  ),
+  (
+    "https://tcgtracking.com/tcgapi/v1/1/sets/2300",
+    include_str!("../fixtures/offline/tcgtracking.com_tcgapi_v1_1_sets_2300.json"),
+  ),
+  (
+    "https://tcgtracking.com/tcgapi/v1/1/sets/2300/pricing",
+    include_str!("../fixtures/offline/tcgtracking.com_tcgapi_v1_1_sets_2300_pricing.json"),
+  ),
+  (
+    "https://tcgtracking.com/tcgapi/v1/1/sets/2300/skus",
+    include_str!("../fixtures/offline/tcgtracking.com_tcgapi_v1_1_sets_2300_skus.json"),
+  ),
+  (
+    "https://api.cardkingdom.com/api/v2/pricelist",
+    include_str!("../fixtures/offline/api.cardkingdom.com_api_v2_pricelist.json"),
+  ),
+  (
+    "https://json.commanderspellbook.com/variants.json",
+    include_str!("../fixtures/offline/json.commanderspellbook.com_variants.json"),
+  ),
+];
+
+pub(crate) struct SyncHttpRequest {
+  pub(crate) url: String,
+  // Names the request in errors, e.g. "Scryfall set list".
+  pub(crate) label: String,
+  pub(crate) timeout: Duration,
+  pub(crate) headers: Vec<(HeaderName, String)>,
+  // Sent as a POST body when set; GET otherwise.
+  pub(crate) json_body: Option<serde_json::Value>,
+}
+
+impl SyncHttpRequest {
+  pub(crate) fn get(url: impl Into<String>, label: impl Into<String>, timeout_secs: u64) -> Self {
+    SyncHttpRequest {
+      url: url.into(),
+      label: label.into(),
+      timeout: Duration::from_secs(timeout_secs),
+      headers: vec![
+        (USER_AGENT, APP_USER_AGENT.to_string()),
+        (ACCEPT, "application/json".to_string()),
+      ],
+      json_body: None,
+    }
+  }
+
+  pub(crate) fn post_json<T: serde::Serialize>(
+    url: impl Into<String>,
+    label: impl Into<String>,
+    timeout_secs: u64,
+    body: &T,
+  ) -> Result<Self, String> {
+    let mut request = SyncHttpRequest::get(url, label, timeout_secs);
+    request.json_body = Some(serde_json::to_value(body).map_err(|e| e.to_string())?);
+    Ok(request)
+  }
+
+  // Sets a header, replacing any default with the same name.
+  pub(crate) fn header(mut self, name: HeaderName, value: &str) -> Self {
+    self.headers.retain(|(existing, _)| *existing != name);
+    self.headers.push((name, value.to_string()));
+    self
+  }
+}
+
+pub(crate) trait SyncHttp: Send + Sync {
+  fn send(&self, request: &SyncHttpRequest) -> Result<Box<dyn Read>, String>;
+
+  // Fixture sources answer every time, so download caches are skipped for them.
+  fn is_live(&self) -> bool {
+    false
+  }
+}
+
+pub(crate) struct LiveSyncHttp;
+
+impl SyncHttp for LiveSyncHttp {
+  fn send(&self, request: &SyncHttpRequest) -> Result<Box<dyn Read>, String> {
+    let client = Client::builder()
+      .timeout(request.timeout)
+      .build()
+      .map_err(|e| e.to_string())?;
+    let mut builder = match &request.json_body {
+      Some(body) => client.post(&request.url).json(body),
+      None => client.get(&request.url),
+    };
+    for (name, value) in &request.headers {
+      builder = builder.header(name.clone(), value.as_str());
+    }
+    let response = builder.send().map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+      return Err(format!("{} failed with status {}", request.label, response.status()));
+    }
+    Ok(Box::new(response))
+  }
+
+  fn is_live(&self) -> bool {
+    true
+  }
+}
+
+// Recorded responses keyed by URL; an Err entry fails the request the way a bad status would.
+pub(crate) struct RecordedSyncHttp {
+  responses: HashMap<String, Result<String, String>>,
+}
+
+impl RecordedSyncHttp {
+  pub(crate) fn new(responses: HashMap<String, Result<String, String>>) -> Self {
+    RecordedSyncHttp { responses }
+  }
+
+  pub(crate) fn bundled() -> Self {
+    RecordedSyncHttp::new(
+      BUNDLED_FIXTURES
+        .iter()
+        .map(|(url, body)| (url.to_string(), Ok(body.to_string())))
+        .collect(),
+    )
+  }
+}
+
+impl SyncHttp for RecordedSyncHttp {
+  fn send(&self, request: &SyncHttpRequest) -> Result<Box<dyn Read>, String> {
+    match self.responses.get(&request.url) {
+      Some(Ok(body)) => Ok(Box::new(Cursor::new(body.clone().into_bytes()))),
+      Some(Err(error)) => Err(error.clone()),
+      None => Err(format!("{} has no recorded response for {}", request.label, request.url)),
+    }
+  }
+}
+
+// Recorded payloads saved as files named by fixture_file_name, e.g. captured with curl.
+pub(crate) struct FixtureDirSyncHttp {
+  dir: PathBuf,
+}
+
+impl FixtureDirSyncHttp {
+  pub(crate) fn new(dir: impl Into<PathBuf>) -> Self {
+    FixtureDirSyncHttp { dir: dir.into() }
+  }
+}
+
+impl SyncHttp for FixtureDirSyncHttp {
+  fn send(&self, request: &SyncHttpRequest) -> Result<Box<dyn Read>, String> {
+    let path = self.dir.join(fixture_file_name(&request.url));
+    let file = fs::File::open(&path)
+      .map_err(|e| format!("{} has no fixture at {}: {}", request.label, path.display(), e))?;
+    Ok(Box::new(file))
+  }
+}
+
+// https://api.scryfall.com/cards/collection -> api.scryfall.com_cards_collection.json
+pub(crate) fn fixture_file_name(url: &str) -> String {
+  let without_scheme = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+  let without_query = without_scheme.split(['?', '#']).next().unwrap_or(without_scheme);
+  let name: String = without_query
+    .trim_end_matches('/')
+    .chars()
+    .map(|ch| if ch.is_ascii_alphanumeric() || ch == '.' || ch == '-' { ch } else { '_' })
+    .collect();
+  if name.ends_with(".json") {
+    name
+  } else {
+    format!("{}.json", name)
+  }
+}
+
+static SYNC_HTTP_OVERRIDE: RwLock<Option<Arc<dyn SyncHttp>>> = RwLock::new(None);
+
+// Replaces the network for every later sync in this process; None restores it.
+pub(crate) fn install_sync_http(client: Option<Arc<dyn SyncHttp>>) {
+  if let Ok(mut current) = SYNC_HTTP_OVERRIDE.write() {
+    *current = client;
+  }
+}
+
+pub(crate) fn active_sync_http() -> Arc<dyn SyncHttp> {
+  #[cfg(any(test, feature = "test-support"))]
+  if let Some(client) = crate::test_support::thread_sync_http() {
+    return client;
+  }
+  SYNC_HTTP_OVERRIDE
+    .read()
+    .ok()
+    .and_then(|current| current.clone())
+    .unwrap_or_else(|| Arc::new(LiveSyncHttp))
+}
+
+#[derive(Clone)]
+pub(crate) struct PayloadArchive {
+  pub(crate) dir: PathBuf,
+  // Copies kept per endpoint; older ones are deleted as new ones land.
+  pub(crate) keep: usize,
+}
+
+static PAYLOAD_ARCHIVE: RwLock<Option<PayloadArchive>> = RwLock::new(None);
+
+// Starts or stops archiving live response bodies; fixture responses are never archived.
+pub(crate) fn configure_payload_archive(archive: Option<PayloadArchive>) {
+  if let Ok(mut current) = PAYLOAD_ARCHIVE.write() {
+    *current = archive;
+  }
+}
+
+// Copies the body into <dir>/<endpoint>/<timestamp>.json.zst while the caller reads it. The file
+// is written under a .partial name and renamed once the whole body is in.
+struct ArchivingReader {
+  inner: Box<dyn Read>,
+  encoder: Option<zstd::stream::write::Encoder<'static, fs::File>>,
+  partial_path: PathBuf,
+  final_path: PathBuf,
+  truncated_path: PathBuf,
+  keep: usize,
+}
+
+impl ArchivingReader {
+  fn finish(&mut self, complete: bool) {
+    let Some(encoder) = self.encoder.take() else {
+      return;
+    };
+    let target = if complete { &self.final_path } else { &self.truncated_path };
+    let saved = encoder.finish().and_then(|_| fs::rename(&self.partial_path, target));
+    if saved.is_err() {
+      let _ = fs::remove_file(&self.partial_path);
+      return;
+    }
+    if let Some(endpoint_dir) = self.final_path.parent() {
+      prune_archived_payloads(endpoint_dir, self.keep);
+    }
+  }
+}
+
+impl Read for ArchivingReader {
+  fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+    let read = self.inner.read(buf)?;
+    if read == 0 {
+      self.finish(true);
+    } else if let Some(encoder) = self.encoder.as_mut() {
+      // Archiving is best effort: a failed write drops the copy, never the sync.
+      if encoder.write_all(&buf[..read]).is_err() {
+        self.encoder = None;
+        let _ = fs::remove_file(&self.partial_path);
+      }
+    }
+    Ok(read)
+  }
+}
+
+// A reader dropped early, e.g. by a parse error or a cancelled sync, keeps only what was read as a
+// .truncated copy; the rest of the body is never downloaded. A parse error is in the bytes read.
+impl Drop for ArchivingReader {
+  fn drop(&mut self) {
+    self.finish(false);
+  }
+}
+
+fn archive_body(request: &SyncHttpRequest, body: Box<dyn Read>) -> Box<dyn Read> {
+  let Some(archive) = PAYLOAD_ARCHIVE.read().ok().and_then(|current| current.clone()) else {
+    return body;
+  };
+  let endpoint = fixture_file_name(&request.url);
+  let endpoint_dir = archive.dir.join(endpoint.trim_end_matches(".json"));
+  let stamp = Utc::now().format(PAYLOAD_ARCHIVE_STAMP_FORMAT).to_string();
+  let final_path = endpoint_dir.join(format!("{}{}", stamp, PAYLOAD_ARCHIVE_SUFFIX));
+  let partial_path = endpoint_dir.join(format!("{}{}.partial", stamp, PAYLOAD_ARCHIVE_SUFFIX));
+  let truncated_path = endpoint_dir.join(format!("{}{}", stamp, PAYLOAD_ARCHIVE_TRUNCATED_SUFFIX));
+  let encoder = fs::create_dir_all(&endpoint_dir)
+    .and_then(|_| fs::File::create(&partial_path))
+    .and_then(|file| zstd::stream::write::Encoder::new(file, 0));
+  match encoder {
+    Ok(encoder) => Box::new(ArchivingReader {
+      inner: body,
+      encoder: Some(encoder),
+      partial_path,
+      final_path,
+      truncated_path,
+      keep: archive.keep.max(1),
+    }),
+    Err(_) => body,
+  }
+}
+
+fn archived_payload_files(endpoint_dir: &Path) -> Vec<PathBuf> {
+  let mut files: Vec<PathBuf> = fs::read_dir(endpoint_dir)
+    .map(|entries| {
+      entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.to_string_lossy().ends_with(PAYLOAD_ARCHIVE_SUFFIX))
+        .collect()
+    })
+    .unwrap_or_default();
+  // Timestamped names sort oldest first.
+  files.sort();
+  files
+}
+
+fn prune_archived_payloads(endpoint_dir: &Path, keep: usize) {
+  let files = archived_payload_files(endpoint_dir);
+  let excess = files.len().saturating_sub(keep);
+  for path in &files[..excess] {
+    let _ = fs::remove_file(path);
+  }
+}
+
+pub(crate) struct ArchivedPayload {
+  // Endpoint folder, named like the --offline-fixture=<dir> file for its URL minus ".json".
+  pub(crate) endpoint: String,
+  pub(crate) captured_at: Option<String>,
+  // The caller stopped reading before the end of the body.
+  pub(crate) truncated: bool,
+  pub(crate) path: PathBuf,
+  pub(crate) compressed_bytes: u64,
+}
+
+// Newest first.
+pub(crate) fn list_archived_payloads(dir: &Path) -> Result<Vec<ArchivedPayload>, String> {
+  if !dir.exists() {
+    return Ok(Vec::new());
+  }
+  let mut payloads = Vec::new();
+  for entry in fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))? {
+    let endpoint_dir = entry.map_err(|e| e.to_string())?.path();
+    if !endpoint_dir.is_dir() {
+      continue;
+    }
+    let endpoint = endpoint_dir
+      .file_name()
+      .map(|name| name.to_string_lossy().to_string())
+      .unwrap_or_default();
+    for path in archived_payload_files(&endpoint_dir) {
+      let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+      let truncated = name.ends_with(PAYLOAD_ARCHIVE_TRUNCATED_SUFFIX);
+      let stamp = name
+        .trim_end_matches(PAYLOAD_ARCHIVE_TRUNCATED_SUFFIX)
+        .trim_end_matches(PAYLOAD_ARCHIVE_SUFFIX);
+      payloads.push(ArchivedPayload {
+        endpoint: endpoint.clone(),
+        captured_at: NaiveDateTime::parse_from_str(stamp, PAYLOAD_ARCHIVE_STAMP_FORMAT)
+          .ok()
+          .map(|captured| captured.and_utc().to_rfc3339()),
+        truncated,
+        compressed_bytes: fs::metadata(&path).map(|metadata| metadata.len()).unwrap_or(0),
+        path,
+      });
+    }
+  }
+  payloads.sort_by(|a, b| b.captured_at.cmp(&a.captured_at).then_with(|| a.endpoint.cmp(&b.endpoint)));
+  Ok(payloads)
+}
+
+fn open_body(request: &SyncHttpRequest) -> Result<Box<dyn Read>, String> {
+  let client = active_sync_http();
+  let body = client.send(request)?;
+  if client.is_live() {
+    Ok(archive_body(request, body))
+  } else {
+    Ok(body)
+  }
+}
+
+pub(crate) fn send_text(request: &SyncHttpRequest) -> Result<String, String> {
+  let mut body = String::new();
+  open_body(request)?
+    .read_to_string(&mut body)
+    .map_err(|e| e.to_string())?;
+  Ok(body)
+}
+
+// Streams the body into T, so large downloads are never held as text.
+pub(crate) fn send_json<T: DeserializeOwned>(request: &SyncHttpRequest) -> Result<T, String> {
+  let reader = open_body(request)?;
+  serde_json::from_reader(BufReader::new(reader)).map_err(|e| format!("Invalid {} response: {}", request.label, e))
+}
+
+// --offline-fixture answers from the bundled samples; --offline-fixture=<dir> from recorded files.
+pub(crate) fn offline_fixture_from_args(args: impl IntoIterator<Item = String>) -> Option<Arc<dyn SyncHttp>> {
+  for arg in args {
+    if arg == OFFLINE_FIXTURE_ARG {
+      return Some(Arc::new(RecordedSyncHttp::bundled()));
+    }
+    if let Some(dir) = arg.strip_prefix(OFFLINE_FIXTURE_ARG).and_then(|rest| rest.strip_prefix('=')) {
+      let dir = dir.trim();
+      if !dir.is_empty() {
+        return Some(Arc::new(FixtureDirSyncHttp::new(dir)));
+      }
+    }
+  }
+  None
+}
@@ -0,0 +1,305 @@
+// Command-layer test harness: an in-memory database built from the full migration set, managed
+// the way `run()` manages the real one, plus seed-data helpers and HTTP fixtures for the syncs.
+// Compiled for `cargo test` and with the `test-support` feature.
+
+use super::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+use sync_http::{RecordedSyncHttp, SyncHttp};
+
+thread_local! {
+  // Commands run on the calling thread and queued jobs carry their caller's, so per-thread fixtures
+  // keep parallel tests apart.
+  static HTTP_FIXTURES: RefCell<HashMap<String, Result<String, String>>> = RefCell::new(HashMap::new());
+  static HTTP_CLIENT: RefCell<Option<Arc<dyn SyncHttp>>> = const { RefCell::new(None) };
+}
+
+// The transport syncs on this thread use: an injected client, else the registered fixtures.
+pub(crate) fn thread_sync_http() -> Option<Arc<dyn SyncHttp>> {
+  if let Some(client) = HTTP_CLIENT.with(|client| client.borrow().clone()) {
+    return Some(client);
+  }
+  HTTP_FIXTURES.with(|fixtures| {
+    let fixtures = fixtures.borrow();
+    if fixtures.is_empty() {
+      None
+    } else {
+      Some(Arc::new(RecordedSyncHttp::new(fixtures.clone())) as Arc<dyn SyncHttp>)
+    }
+  })
+}
+
+// Routes this thread's syncs through `client`, e.g. FixtureDirSyncHttp over recorded payloads.
+pub(crate) fn use_sync_http(client: Arc<dyn SyncHttp>) {
+  HTTP_CLIENT.with(|current| *current.borrow_mut() = Some(client));
+}
+
+// Answers GET/POST requests to `url` with `body` until the harness is dropped. Once any fixture is
+// registered, requests without one fail instead of reaching the network.
+pub(crate) fn mock_http(url: &str, body: impl Into<String>) {
+  HTTP_FIXTURES.with(|fixtures| {
+    fixtures.borrow_mut().insert(url.to_string(), Ok(body.into()));
+  });
+}
+
+pub(crate) fn mock_http_json(url: &str, body: &serde_json::Value) {
+  mock_http(url, body.to_string());
+}
+
+// Makes requests to `url` fail with `error`, as a non-success status or network error would.
+pub(crate) fn mock_http_error(url: &str, error: &str) {
+  HTTP_FIXTURES.with(|fixtures| {
+    fixtures.borrow_mut().insert(url.to_string(), Err(error.to_string()));
+  });
+}
+
+pub(crate) fn clear_http_mocks() {
+  HTTP_FIXTURES.with(|fixtures| fixtures.borrow_mut().clear());
+  HTTP_CLIENT.with(|client| *client.borrow_mut() = None);
+}
+
+// A Scryfall card payload with every field the importers read. `extra` overrides or adds fields.
+pub(crate) fn scryfall_card_fixture(
+  scryfall_id: &str,
+  name: &str,
+  set_code: &str,
+  extra: serde_json::Value,
+) -> serde_json::Value {
+  let mut card = serde_json::json!({
+    "id": scryfall_id,
+    "oracle_id": format!("oracle-{}", scryfall_id),
+    "name": name,
+    "set": set_code,
+    "set_name": set_code.to_uppercase(),
+    "collector_number": "1",
+    "released_at": "2024-01-01",
+    "lang": "en",
+    "mana_cost": "{1}{G}",
+    "type_line": "Creature",
+    "oracle_text": "",
+    "cmc": 2.0,
+    "colors": ["G"],
+    "color_identity": ["G"],
+    "rarity": "common",
+    "finishes": ["nonfoil", "foil"],
+    "legalities": {"standard": "legal", "modern": "legal", "commander": "legal"},
+  });
+  if let (serde_json::Value::Object(fields), serde_json::Value::Object(overrides)) = (&mut card, extra) {
+    fields.extend(overrides);
+  }
+  card
+}
+
+pub(crate) struct TestApp {
+  app: tauri::App<tauri::test::MockRuntime>,
+  data_dir: PathBuf,
+  // A memdb database is freed with its last connection; this one keeps it for the harness lifetime.
+  _keep_alive: Connection,
+}
+
+impl TestApp {
+  pub(crate) fn new() -> Result<Self, String> {
+    let name = format!("magiccollection-test-{}", Uuid::new_v4());
+    // Attachments, exports and the CK price cache still need a real directory.
+    let data_dir = std::env::temp_dir().join(&name);
+    fs::create_dir_all(&data_dir).map_err(|e| e.to_string())?;
+    let db_path = PathBuf::from(format!("file:/{}?vfs=memdb", name));
+    let keep_alive = Connection::open(&db_path).map_err(|e| e.to_string())?;
+    init_database(&db_path)?;
+
+    // Native notifications need the plugin and a desktop session, so tests keep them off.
+    let connection = open_database(&db_path)?;
+    for event_type in [OS_NOTIFY_ALERT, OS_NOTIFY_SYNC_COMPLETE, OS_NOTIFY_JOB_FAILED] {
+      write_sync_setting(&connection, &os_notification_setting_key(event_type), Some("0"))?;
+    }
+
+    let state = AppState::new(data_dir.clone(), data_dir.clone());
+    state.switch_database(db_path);
+    let app = tauri::test::mock_builder()
+      .manage(state)
+      .manage(BackgroundState::default())
+      .manage(dashboard_feed::DashboardFeedState::default())
+      .build(tauri::test::mock_context(tauri::test::noop_assets()))
+      .map_err(|e| e.to_string())?;
+    Ok(TestApp {
+      app,
+      data_dir,
+      _keep_alive: keep_alive,
+    })
+  }
+
+  pub(crate) fn state(&self) -> State<'_, AppState> {
+    self.app.state::<AppState>()
+  }
+
+  pub(crate) fn handle(&self) -> AppHandle<tauri::test::MockRuntime> {
+    self.app.handle().clone()
+  }
+
+  pub(crate) fn data_dir(&self) -> &Path {
+    &self.data_dir
+  }
+
+  pub(crate) fn connection(&self) -> Result<Connection, String> {
+    open_database(&self.state().db_path())
+  }
+
+  // Queues `sync_all_sources_now` and waits for its job to finish.
+  pub(crate) fn run_full_sync(&self) -> Result<FullSourceSyncResultDto, String> {
+    let job = sync_all_sources_now(self.handle())?;
+    let finished = wait_for_job(&self.state(), &job.job_id)?;
+    serde_json::from_value(finished_job_result(finished)?).map_err(|e| e.to_string())
+  }
+
+  pub(crate) fn seed_profile(&self, name: &str) -> Result<String, String> {
+    create_profile(self.state(), name.to_string()).map(|profile| profile.id)
+  }
+
+  // Seeds the catalog with scryfall_card_fixture(...) payloads; returns the printing ids.
+  pub(crate) fn seed_cards(&self, cards: &[serde_json::Value]) -> Result<Vec<String>, String> {
+    let connection = self.connection()?;
+    let mut ids = Vec::with_capacity(cards.len());
+    for card in cards {
+      let card: ScryfallCollectionCard = serde_json::from_value(card.clone()).map_err(|e| e.to_string())?;
+      upsert_scryfall_oracle_if_changed(&connection, &card)?;
+      ids.push(card.id.trim().to_lowercase());
+    }
+    Ok(ids)
+  }
+
+  // Adds NM English copies without a location; returns the owned row id.
+  pub(crate) fn seed_owned(
+    &self,
+    profile_id: &str,
+    scryfall_id: &str,
+    quantity: i64,
+    foil_quantity: i64,
+  ) -> Result<String, String> {
+    let connection = self.connection()?;
+    let key = owned_row_key_from_input(&connection, profile_id, None, None, None, false)?
+      .ok_or_else(|| "Default owned row key could not be built.".to_string())?;
+    let mut owned_item_id = String::new();
+    if quantity > 0 {
+      owned_item_id = add_owned_copies(&connection, profile_id, scryfall_id, &key, false, quantity)?;
+    }
+    if foil_quantity > 0 {
+      owned_item_id = add_owned_copies(&connection, profile_id, scryfall_id, &key, true, foil_quantity)?;
+    }
+    Ok(owned_item_id)
+  }
+
+  // Writes an NM nonfoil TCGplayer price row captured at `captured_at` (RFC 3339).
+  pub(crate) fn seed_price(&self, scryfall_id: &str, tcg_market: f64, captured_at: &str) -> Result<(), String> {
+    let connection = self.connection()?;
+    let captured_ymd = captured_ymd_from_iso(captured_at).ok_or_else(|| format!("Invalid timestamp {}", captured_at))?;
+    upsert_compact_price_row(
+      &connection,
+      scryfall_id,
+      Some(CONDITION_NM_ID),
+      Some(FINISH_NONFOIL_ID),
+      Some(tcg_market),
+      Some(tcg_market),
+      Some(tcg_market),
+      None,
+      None,
+      None,
+      &sync_version_from_iso(captured_at),
+      captured_ymd,
+      captured_at,
+    )
+  }
+}
+
+impl Drop for TestApp {
+  fn drop(&mut self) {
+    clear_http_mocks();
+    let _ = fs::remove_dir_all(&self.data_dir);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use sync_http::FixtureDirSyncHttp;
+
+  #[test]
+  fn seeded_rows_read_back_through_the_commands() {
+    let app = TestApp::new().unwrap();
+    let profile_id = app.seed_profile("Tester").unwrap();
+    let ids = app
+      .seed_cards(&[scryfall_card_fixture("c1", "Llanowar Elves", "dom", serde_json::json!({}))])
+      .unwrap();
+    let owned_item_id = app.seed_owned(&profile_id, &ids[0], 3, 1).unwrap();
+    app.seed_price(&ids[0], 0.5, "2026-10-01T00:00:00Z").unwrap();
+
+    let rows = load_collection_rows(&app.connection().unwrap(), &profile_id).unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].owned_item_id, owned_item_id);
+    assert_eq!((rows[0].quantity, rows[0].foil_quantity), (3, 1));
+    assert_eq!(rows[0].current_price, Some(0.5));
+  }
+
+  #[test]
+  fn full_sync_runs_against_mocked_sources() {
+    let app = TestApp::new().unwrap();
+    mock_http_json("https://tcgtracking.com/tcgapi/v1/1/sets", &serde_json::json!({"sets": []}));
+    mock_http_json(
+      CK_PRICELIST_URL,
+      &serde_json::json!({"data": [{"scryfall_id": "c1", "is_foil": "false", "price_buy": "0.10", "price_sell": "0.40"}]}),
+    );
+    mock_http_json(
+      "https://api.scryfall.com/bulk-data",
+      &serde_json::json!({"data": [{"type": "default_cards", "download_uri": "https://mock/default.json"}]}),
+    );
+    mock_http_json(
+      "https://mock/default.json",
+      &serde_json::json!([
+        scryfall_card_fixture("c1", "Llanowar Elves", "dom", serde_json::json!({})),
+        scryfall_card_fixture("c2", "Giant Growth", "dom", serde_json::json!({"collector_number": "2"})),
+      ]),
+    );
+    mock_http_json(
+      "https://api.scryfall.com/sets",
+      &serde_json::json!({"data": [{"code": "dom", "name": "Dominaria", "released_at": "2018-04-27"}]}),
+    );
+
+    let result = app.run_full_sync().unwrap();
+    assert_eq!(
+      (result.scryfall_scanned, result.scryfall_sets_synced, result.ck_scanned),
+      (2, 1, 1)
+    );
+
+    mock_http_error("https://api.scryfall.com/sets", "503 Service Unavailable");
+    assert!(sync_scryfall_sets(app.handle()).err().unwrap().contains("503"));
+    let result = app.run_full_sync().unwrap();
+    assert_eq!((result.scryfall_scanned, result.scryfall_sets_synced), (2, 0));
+    assert!(result.sync_warnings[0].contains("503"), "{:?}", result.sync_warnings);
+    let (synced_version, _, _) = read_catalog_sync_row(&app.connection().unwrap(), CATALOG_DATASET_DEFAULT).unwrap();
+    assert_eq!(synced_version.as_deref(), Some(result.sync_version.as_str()));
+
+    let data_dir = app.data_dir().to_path_buf();
+    drop(app);
+    assert!(!data_dir.exists());
+    assert!(thread_sync_http().is_none());
+  }
+
+  #[test]
+  fn full_sync_runs_against_the_offline_fixture_dir() {
+    let app = TestApp::new().unwrap();
+    use_sync_http(Arc::new(FixtureDirSyncHttp::new(
+      Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join("offline"),
+    )));
+
+    let result = app.run_full_sync().unwrap();
+    assert_eq!(
+      (result.scryfall_scanned, result.scryfall_sets_synced, result.ck_scanned),
+      (5, 4, 5)
+    );
+
+    let profile_id = app.seed_profile("Demo").unwrap();
+    app.seed_owned(&profile_id, "00000000-0000-4000-8000-000000000004", 1, 0).unwrap();
+    let rows = load_collection_rows(&app.connection().unwrap(), &profile_id).unwrap();
+    assert_eq!(rows.len(), 1);
+  }
+}
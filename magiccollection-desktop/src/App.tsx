@@ -11,6 +11,7 @@ import {
   asCardMap,
   bulkUpdateTags,
   createProfile,
+  followActiveJob,
   getCollection,
   hydrateProfileCardMetadata,
   importCollectionRows,
@@ -23,6 +24,7 @@ import {
   syncCkPricesIntoCardData,
   updateOwnedCardMetadata,
   updateCardQuantity,
+  type HydrationProgress,
 } from './lib/backend'
 import { recordPerfMetric } from './lib/perfMetrics'
 import {
@@ -237,7 +239,7 @@ function App() {
             name: card.name,
             setCode: card.setCode,
             collectorNumber: card.collectorNumber,
-            imageUrl: card.imageUrl,
+            imageUrl: card.scryfallImageUrl ?? card.imageUrl,
             marketPrice: local.marketPrice,
           }
         })
@@ -615,7 +617,7 @@ function App() {
     setSyncProgressText('')
     setIsSyncing(true)
     try {
-      const cards = await addCardToCollection({
+      const { cards } = await addCardToCollection({
         ...input,
         profileId: activeProfile.id,
       })
@@ -642,7 +644,7 @@ function App() {
     applyLocalQuantityDelta(cardId, foil, 1)
     setIsSyncing(true)
     try {
-      const cards = await updateCardQuantity({
+      const { cards } = await updateCardQuantity({
         profileId: activeProfile.id,
         scryfallId: cardId,
         foil,
@@ -672,7 +674,7 @@ function App() {
     applyLocalQuantityDelta(cardId, foil, -1)
     setIsSyncing(true)
     try {
-      const cards = await updateCardQuantity({
+      const { cards } = await updateCardQuantity({
         profileId: activeProfile.id,
         scryfallId: cardId,
         foil,
@@ -760,7 +762,7 @@ function App() {
     setSyncProgressText('')
     setIsSyncing(true)
     try {
-      const cards = await bulkUpdateTags({
+      const { cards } = await bulkUpdateTags({
         profileId: activeProfile.id,
         scryfallIds: [cardId],
         tags: [tag],
@@ -898,7 +900,29 @@ function App() {
       setSyncProgressPct(12)
       setSyncProgressText('Syncing TCGTracking + Card Kingdom + Scryfall')
       ensureNotCanceled()
-      const fullSyncResult = await syncAllSourcesNow()
+      const fullSyncResult = await syncAllSourcesNow({
+        signal: refreshController.signal,
+        onProgress: (job) => {
+          const counters = job.progress
+          if (!counters) {
+            return
+          }
+          if (job.phase === 'tcgtracking') {
+            const share = counters.tcgSetsTotal > 0 ? counters.tcgSetsScanned / counters.tcgSetsTotal : 0
+            setSyncProgressPct(12 + Math.round(share * 48))
+            setSyncProgressText(`TCGTracking set ${counters.tcgSetsScanned}/${counters.tcgSetsTotal}`)
+          } else if (job.phase === 'cardkingdom') {
+            setSyncProgressPct(62)
+            setSyncProgressText('Card Kingdom prices')
+          } else if (job.phase === 'scryfall_cards') {
+            setSyncProgressPct(72)
+            setSyncProgressText(`Scryfall cards (${counters.scryfallScanned} read)`)
+          } else if (job.phase === 'scryfall_sets' || job.phase === 'finalizing') {
+            setSyncProgressPct(88)
+            setSyncProgressText('Scryfall sets')
+          }
+        },
+      })
       setSyncProgressPct(94)
       setSyncProgressText('Refreshing collection state')
       ensureNotCanceled()
@@ -915,7 +939,12 @@ function App() {
       }
       setSyncProgressPct(100)
       setSyncProgressText('Complete')
-      return `Sync ${fullSyncResult.syncVersion}: Scryfall ${fullSyncResult.scryfallUpdated}/${fullSyncResult.scryfallScanned} updated, TCG ${fullSyncResult.tcgPriceUpserts} price upserts, CK sell ${fullSyncResult.ckUpsertedSell}, CK buylist ${fullSyncResult.ckUpsertedBuylist}.`
+      const malformedRecords = fullSyncResult.tcgMalformedRecords + fullSyncResult.ckMalformedRecords
+      const skippedNote =
+        malformedRecords > 0 || fullSyncResult.tcgSetsFailed > 0
+          ? ` Skipped ${malformedRecords} malformed vendor records and ${fullSyncResult.tcgSetsFailed} TCG sets.`
+          : ''
+      return `Sync ${fullSyncResult.syncVersion}: Scryfall ${fullSyncResult.scryfallUpdated}/${fullSyncResult.scryfallScanned} updated, TCG ${fullSyncResult.tcgPriceUpserts} price upserts, CK sell ${fullSyncResult.ckUpsertedSell}, CK buylist ${fullSyncResult.ckUpsertedBuylist}.${skippedNote}`
     } catch (error) {
       if (refreshController.signal.aborted) {
         return 'Sync canceled.'
@@ -961,6 +990,16 @@ function App() {
       })
       setOwnedCards(asCardMap(cards))
       await hydrateMissingPricesForProfile(activeProfile.id, cards, { force: true })
+      const hydration = await followActiveJob('hydrate_metadata', (job) => {
+        const progress = job.progress as HydrationProgress | null
+        if (progress && progress.total > 0) {
+          setSyncProgressPct(Math.round((progress.processed / progress.total) * 100))
+          setSyncProgressText('Hydrating imported cards')
+        }
+      })
+      if (hydration?.status === 'completed') {
+        setOwnedCards(asCardMap(await getCollection(activeProfile.id)))
+      }
     } catch (error) {
       const message =
         error instanceof Error ? error.message : 'Unable to import mapped collection rows.'
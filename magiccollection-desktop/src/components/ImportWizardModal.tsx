@@ -1,11 +1,12 @@
 import { useMemo, useState } from 'react'
-import type { CollectionImportRow } from '../types'
+import type { CollectionImportRow, ImportDuplicateWarning } from '../types'
 import {
   IMPORT_FIELD_DEFINITIONS,
   type DelimitedImportMapping,
   type DelimiterMode,
   type ImportFieldKey,
   buildDefaultMapping,
+  importRowAggregateKey,
   parseDelimitedContent,
   transformDelimitedRowsToImport,
 } from '../lib/importers/delimited'
@@ -47,6 +48,7 @@ function emptyMapping(): DelimitedImportMapping {
     condition: null,
     language: null,
     purchasePrice: null,
+    purchaseCurrency: null,
     dateAdded: null,
     typeLine: null,
     manaValue: null,
@@ -79,6 +81,7 @@ export function ImportWizardModal({
     copiesImported: number
     rowsSkipped: number
     skippedDetails: Array<{ rowNumber: number; reason: string; preview: string }>
+    duplicateWarnings: ImportDuplicateWarning[]
   } | null>(null)
   const [showReport, setShowReport] = useState(false)
 
@@ -295,8 +298,7 @@ export function ImportWizardModal({
 
         if (missingId && !missingSetCollector && !resolved?.id) {
           additionalSkipped += 1
-          const fallbackKey = `${row.setCode.toLowerCase()}|${row.collectorNumber}|${row.name.toLowerCase()}`
-          const rowNumbers = transformed.sourceRowNumbersByKey[fallbackKey] ?? []
+          const rowNumbers = transformed.sourceRowNumbersByKey[importRowAggregateKey(row)] ?? []
           if (rowNumbers.length) {
             for (const rowNumber of rowNumbers) {
               additionalSkippedDetails.push({
@@ -317,8 +319,7 @@ export function ImportWizardModal({
 
         if (missingId && !missingSetCollector && !resolved?.id) {
           additionalSkipped += 1
-          const fallbackKey = `${row.setCode.toLowerCase()}|${row.collectorNumber}|${row.name.toLowerCase()}`
-          const rowNumbers = transformed.sourceRowNumbersByKey[fallbackKey] ?? []
+          const rowNumbers = transformed.sourceRowNumbersByKey[importRowAggregateKey(row)] ?? []
           if (rowNumbers.length) {
             for (const rowNumber of rowNumbers) {
               additionalSkippedDetails.push({
@@ -381,6 +382,7 @@ export function ImportWizardModal({
         copiesImported: transformed.copiesImported,
         rowsSkipped: transformed.rowsSkipped,
         skippedDetails: transformed.skippedDetails,
+        duplicateWarnings: transformed.duplicateWarnings,
       })
       setShowReport(false)
     } catch (importError) {
@@ -602,6 +604,38 @@ export function ImportWizardModal({
                 </table>
               </div>
             )}
+            <h4>Duplicate Rows</h4>
+            {lastImportReport.duplicateWarnings.length === 0 ? (
+              <p className="muted small">No duplicate rows.</p>
+            ) : (
+              <div className="import-preview-table-wrap">
+                <table className="import-preview-table">
+                  <thead>
+                    <tr>
+                      <th>Rows</th>
+                      <th>Card</th>
+                      <th>Result</th>
+                    </tr>
+                  </thead>
+                  <tbody>
+                    {lastImportReport.duplicateWarnings.slice(0, 500).map((item, index) => (
+                      <tr key={`duplicate-${index}`}>
+                        <td>{item.rowNumbers.join(', ')}</td>
+                        <td>{item.name}</td>
+                        <td>
+                          {item.action === 'split'
+                            ? 'Kept apart (different condition, language or location)'
+                            : 'Merged into one row'}
+                          {item.conflictingFields.length
+                            ? `; kept the first ${item.conflictingFields.join(', ')}`
+                            : ''}
+                        </td>
+                      </tr>
+                    ))}
+                  </tbody>
+                </table>
+              </div>
+            )}
           </div>
         ) : null}
         </div>
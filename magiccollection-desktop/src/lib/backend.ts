@@ -1,25 +1,154 @@
 import { invoke } from '@tauri-apps/api/core'
+import { decodeCompactRows } from './msgpack'
 import { loadCollection, loadProfiles, saveCollection, saveProfiles } from './storage'
 import type {
+  ActivityFeed,
   AddCardInput,
+  AppDataLocation,
+  ArchivedPayload,
+  Attachment,
+  Audit,
+  AuditCountInput,
+  BackgroundStatus,
+  BulkMoveToLocationInput,
+  BulkOwnedCardTarget,
   BulkTagRequest,
+  BulkUpdateOwnedCardMetadataInput,
+  BuylistArbitrage,
+  CardAlias,
+  CardNameAutocomplete,
+  CardSearchHit,
+  CollectionExport,
+  CollectionExportDialect,
+  CollectionExportFormat,
+  CollectionFacets,
+  CollectionMutationResult,
+  CollectionPage,
+  CollectionPageInput,
+  CollectionTotals,
+  CollectionValuation,
+  CollectionValueHistory,
   CollectionImportRow,
+  CsvImportFormat,
+  CsvImportSummary,
+  ComboReport,
+  ComboSyncResult,
+  ConditionMultipliers,
+  DashboardFeedStatus,
+  Deck,
+  DeckBoard,
+  DeckExport,
+  DeckImportResult,
+  DemandReport,
+  DemandReportRequest,
+  DemandSyncResult,
+  DigitalImportSummary,
+  DigitalItem,
+  DigitalPaperReport,
+  DigitalPlatform,
+  ExportTemplate,
   FilterToken,
+  FullSourceSyncResult,
+  FxRateSyncResult,
+  ImagePrefetchStatus,
+  ImportDuplicatePolicy,
+  ImportFormatDetection,
+  InsuranceReport,
+  InsuranceValuationMode,
+  InventoryFeed,
+  InventoryFeedRequest,
+  Job,
+  JobKind,
+  Kit,
+  KitItemInput,
+  LanguagePremiums,
+  LanguageReport,
+  LanguageReportRequest,
+  LimitedEvent,
+  LimitedEventFormat,
+  LimitedPoolCardInput,
+  Location,
   MarketSnapshotInput,
   MarketTrend,
   OwnedCard,
   OwnedCardMap,
+  OracleChange,
+  OsNotificationSettings,
+  PayloadArchiveSettings,
+  OutOfHouseReport,
+  OwnedRowTarget,
+  PerformanceMetrics,
+  PortfolioReport,
+  PreviouslyOwnedCard,
+  PreviousOwnership,
+  PriceAlert,
+  PriceAlertEvent,
+  PriceAlertInput,
   PriceDirection,
+  PriceHistory,
+  PricingPreview,
+  PricingRules,
+  PrintList,
+  PrintListOptions,
+  PrintingUpgradePlan,
+  PrintingUpgradeRequest,
   Profile,
+  ProfitLossReport,
+  QuantityReason,
+  RemovedCard,
+  RenderedExport,
+  Reservation,
+  ResolvedCardName,
+  Sale,
+  SaleLineInput,
+  SaleListing,
+  SaleListingTemplate,
+  SalesReport,
+  SaveExportTemplateInput,
+  SetAlias,
+  SyncJob,
+  Tag,
+  TopMovers,
+  TradeFodderReport,
+  TradeFodderRequest,
+  TradeScope,
+  TradeStatus,
   UpdateOwnedCardMetadataInput,
+  ValueInUnits,
+  ValueSnapshot,
+  Workspace,
 } from '../types'
 
 const MARKET_SNAPSHOT_KEY = 'magiccollection.market-snapshots.v1'
+const SYNC_JOB_POLL_MS = 500
 
 export interface HydrateProfileCardMetadataResult {
   attempted: number
   hydrated: number
   remaining: number
+  // Images of the printings an import hydration job covered. The job also queues them for the
+  // image cache.
+  imageUrls: string[]
+}
+
+// Progress of a running hydrate_metadata job.
+export interface HydrationProgress {
+  total: number
+  processed: number
+  hydrated: number
+}
+
+export interface PriceChannel {
+  id: string
+  label: string
+  vendor: string
+  kind: 'retail' | 'buylist'
+  currency: string
+  sourceId: string
+  enabled: boolean
+  lastSyncedAt: string | null
+  lastCapturedAt: string | null
+  coverageCount: number
 }
 
 interface PriceHistoryEntry {
@@ -94,6 +223,7 @@ function trendForIdLocal(scryfallId: string): MarketTrend {
   const delta = current !== null && previous !== null ? current - previous : null
   return {
     scryfallId,
+    foil: false,
     currentPrice: current,
     previousPrice: previous,
     priceDelta: delta,
@@ -152,6 +282,10 @@ function normalizeCollectionCard(
       typeof input.purchasePrice === 'number' && Number.isFinite(input.purchasePrice)
         ? input.purchasePrice
         : null,
+    purchaseCurrency:
+      typeof input.purchasePrice === 'number' && Number.isFinite(input.purchasePrice)
+        ? input.purchaseCurrency ?? 'USD'
+        : null,
     dateAdded: input.dateAdded ?? null,
   }
 }
@@ -319,6 +453,10 @@ async function fallbackImportCollectionRows(input: {
         typeof row.purchasePrice === 'number' && Number.isFinite(row.purchasePrice)
           ? row.purchasePrice
           : existing?.purchasePrice ?? null,
+      purchaseCurrency:
+        typeof row.purchasePrice === 'number' && Number.isFinite(row.purchasePrice)
+          ? row.purchaseCurrency ?? 'USD'
+          : existing?.purchaseCurrency ?? null,
       dateAdded: row.dateAdded ?? existing?.dateAdded ?? null,
       updatedAt: nowIso(),
     })
@@ -355,9 +493,17 @@ async function fallbackBulkUpdateTags(input: BulkTagRequest): Promise<OwnedCard[
       continue
     }
 
-    const merged = new Set(existing.tags)
-    for (const tag of manualTags) {
-      merged.add(tag)
+    const mode = input.mode ?? 'add'
+    const removed = new Set(manualTags.map((tag) => tag.toLowerCase()))
+    const merged = new Set(
+      mode === 'replace'
+        ? []
+        : existing.tags.filter((tag) => mode !== 'remove' || !removed.has(tag.toLowerCase())),
+    )
+    if (mode !== 'remove') {
+      for (const tag of manualTags) {
+        merged.add(tag)
+      }
     }
 
     const nextTags = input.includeAutoRules
@@ -408,6 +554,12 @@ async function fallbackUpdateOwnedCardMetadata(
         : input.purchasePrice === null
           ? null
           : existing.purchasePrice ?? null,
+    purchaseCurrency:
+      typeof input.purchasePrice === 'number' && Number.isFinite(input.purchasePrice)
+        ? input.purchaseCurrency?.trim().toUpperCase() || existing.purchaseCurrency || 'USD'
+        : input.purchasePrice === null
+          ? null
+          : existing.purchaseCurrency ?? null,
     dateAdded:
       typeof input.dateAdded === 'string'
         ? input.dateAdded.trim() || null
@@ -465,31 +617,100 @@ export async function getCollection(profileId: string): Promise<OwnedCard[]> {
   if (!hasTauriRuntime()) {
     return fallbackGetCollection(profileId)
   }
-  return invoke<OwnedCard[]>('get_collection', { profileId })
+  const payload = await invoke<ArrayBuffer>('get_collection', { profileId, encoding: 'msgpack' })
+  return decodeCompactRows<OwnedCard>(payload)
 }
 
-export async function addCardToCollection(input: AddCardInput): Promise<OwnedCard[]> {
+// Only the requested OwnedCard fields (plus ownedItemId); skips tag and price lookups not asked for.
+export async function getCollectionFields<K extends keyof OwnedCard>(
+  profileId: string,
+  fields: K[],
+): Promise<Array<Pick<OwnedCard, K | 'ownedItemId'>>> {
   if (!hasTauriRuntime()) {
-    return fallbackAddCardToCollection(input)
+    return fallbackGetCollection(profileId)
   }
-  return invoke<OwnedCard[]>('add_card_to_collection', { input })
+  const payload = await invoke<ArrayBuffer>('get_collection', { profileId, fields, encoding: 'msgpack' })
+  return decodeCompactRows<Pick<OwnedCard, K | 'ownedItemId'>>(payload)
 }
 
-export async function updateCardQuantity(input: {
-  profileId: string
-  scryfallId: string
-  foil: boolean
-  delta: number
-}): Promise<OwnedCard[]> {
+// Sorted, filtered and paged in SQL. The browser fallback only pages the stored collection.
+export async function getCollectionPage(input: CollectionPageInput): Promise<CollectionPage> {
+  if (!hasTauriRuntime()) {
+    const cards = await fallbackGetCollection(input.profileId)
+    const offset = Math.max(0, input.offset ?? 0)
+    const limit = input.limit ?? 200
+    return {
+      rows: cards.slice(offset, offset + limit),
+      totalCount: cards.length,
+      offset,
+      limit,
+      sortKey: 'name',
+      sortDirection: 'asc',
+      nextCursor: null,
+    }
+  }
+  return invoke<CollectionPage>('get_collection_page', { input })
+}
+
+function fallbackMutationResult(
+  cards: OwnedCard[],
+  scryfallIds: string[],
+): CollectionMutationResult {
+  // Browser fallback keys rows by scryfall id.
+  const owned = new Set(cards.map((card) => card.scryfallId))
+  return { cards, affectedOwnedItemIds: scryfallIds.filter((id) => owned.has(id)) }
+}
+
+export async function addCardToCollection(
+  input: AddCardInput,
+): Promise<CollectionMutationResult> {
+  if (!hasTauriRuntime()) {
+    return fallbackMutationResult(await fallbackAddCardToCollection(input), [input.scryfallId])
+  }
+  return invoke<CollectionMutationResult>('add_card_to_collection', { input })
+}
+
+export async function addCardById(
+  input: OwnedRowTarget & {
+    profileId: string
+    scryfallId: string
+    foil: boolean
+    quantity?: number
+  },
+): Promise<CollectionMutationResult> {
+  if (!hasTauriRuntime()) {
+    // Browser fallback has no catalog; it can only add copies of cards already in the collection.
+    if (!loadCollection(input.profileId)[input.scryfallId]) {
+      throw new Error('Adding a card by id requires the desktop catalog.')
+    }
+    return fallbackMutationResult(
+      await fallbackUpdateCardQuantity({ ...input, delta: input.quantity ?? 1 }),
+      [input.scryfallId],
+    )
+  }
+  return invoke<CollectionMutationResult>('add_card_by_id', { input })
+}
+
+export async function updateCardQuantity(
+  input: OwnedRowTarget & {
+    profileId: string
+    scryfallId: string
+    foil: boolean
+    delta: number
+    reason?: QuantityReason
+  },
+): Promise<CollectionMutationResult> {
   if (!hasTauriRuntime()) {
-    return fallbackUpdateCardQuantity(input)
+    return fallbackMutationResult(await fallbackUpdateCardQuantity(input), [input.scryfallId])
   }
-  return invoke<OwnedCard[]>('update_card_quantity', { input })
+  return invoke<CollectionMutationResult>('update_card_quantity', { input })
 }
 
 export async function removeCardFromCollection(input: {
   profileId: string
   scryfallId: string
+  // Removes only this owned row instead of every row of the printing.
+  ownedItemId?: string
 }): Promise<OwnedCard[]> {
   if (!hasTauriRuntime()) {
     return fallbackRemoveCardFromCollection(input)
@@ -497,11 +718,10 @@ export async function removeCardFromCollection(input: {
   return invoke<OwnedCard[]>('remove_card_from_collection', { input })
 }
 
-export async function removeCardsFromCollection(input: {
-  profileId: string
-  scryfallIds: string[]
-}): Promise<OwnedCard[]> {
-  if (!input.scryfallIds.length) {
+export async function removeCardsFromCollection(
+  input: BulkOwnedCardTarget,
+): Promise<OwnedCard[]> {
+  if (!input.scryfallIds.length && !input.ownedItemIds?.length && !input.filterQuery?.trim()) {
     return getCollection(input.profileId)
   }
   if (!hasTauriRuntime()) {
@@ -515,6 +735,27 @@ export async function removeCardsFromCollection(input: {
   return invoke<OwnedCard[]>('remove_cards_from_collection', { input })
 }
 
+export async function listRecentlyRemoved(
+  profileId: string,
+  limit?: number,
+): Promise<RemovedCard[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<RemovedCard[]>('list_recently_removed', { input: { profileId, limit } })
+}
+
+export async function restoreRemovedCard(input: {
+  profileId: string
+  removedIds?: string[]
+  batchId?: string
+}): Promise<OwnedCard[]> {
+  if (!hasTauriRuntime()) {
+    return fallbackGetCollection(input.profileId)
+  }
+  return invoke<OwnedCard[]>('restore_removed_card', { input })
+}
+
 export async function recordMarketSnapshots(
   snapshots: MarketSnapshotInput[],
 ): Promise<void> {
@@ -527,8 +768,10 @@ export async function recordMarketSnapshots(
   await invoke('record_market_snapshots', { snapshots })
 }
 
+// `foil` reads foil prices, falling back to nonfoil when a printing has none.
 export async function getMarketPriceTrends(
   scryfallIds: string[],
+  foil?: boolean,
 ): Promise<MarketTrend[]> {
   if (!scryfallIds.length) {
     return []
@@ -538,12 +781,147 @@ export async function getMarketPriceTrends(
   }
   return invoke<MarketTrend[]>('get_market_price_trends', {
     scryfallIds,
+    foil,
+  })
+}
+
+// `source` is a price channel id (default tcg-market); `days` defaults to 90.
+export async function getPriceHistory(input: {
+  scryfallId: string
+  source?: string
+  days?: number
+  foil?: boolean
+}): Promise<PriceHistory> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Price history requires the desktop app.')
+  }
+  return invoke<PriceHistory>('get_price_history', {
+    scryfallId: input.scryfallId,
+    source: input.source ?? null,
+    days: input.days ?? null,
+    foil: input.foil ?? null,
+  })
+}
+
+export async function getTopMovers(input: {
+  profileId: string
+  windowDays?: number
+  limit?: number
+}): Promise<TopMovers> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Top movers require the desktop app.')
+  }
+  return invoke<TopMovers>('get_top_movers', {
+    profileId: input.profileId,
+    windowDays: input.windowDays ?? null,
+    limit: input.limit ?? null,
   })
 }
 
+// `minSpread` is the lowest buylist-over-TCGplayer percent to list; the backend defaults to -10.
+export async function findBuylistArbitrage(input: {
+  profileId: string
+  minSpread?: number
+}): Promise<BuylistArbitrage> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Buylist arbitrage requires the desktop app.')
+  }
+  return invoke<BuylistArbitrage>('find_buylist_arbitrage', {
+    profileId: input.profileId,
+    minSpread: input.minSpread ?? null,
+  })
+}
+
+export async function listPriceAlerts(profileId: string): Promise<PriceAlert[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<PriceAlert[]>('list_price_alerts', { profileId })
+}
+
+export async function createPriceAlert(input: PriceAlertInput): Promise<PriceAlert> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Price alerts require the desktop app.')
+  }
+  return invoke<PriceAlert>('create_price_alert', {
+    input: {
+      ...input,
+      sourceId: input.sourceId ?? null,
+      foil: input.foil ?? null,
+    },
+  })
+}
+
+export async function deletePriceAlert(profileId: string, alertId: string): Promise<void> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Price alerts require the desktop app.')
+  }
+  await invoke('delete_price_alert', { profileId, alertId })
+}
+
+// Firings newest first; pass the last seen `firedAt` as `since` to poll for new ones.
+export async function listPriceAlertEvents(input: {
+  profileId: string
+  since?: string
+  limit?: number
+}): Promise<PriceAlertEvent[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<PriceAlertEvent[]>('list_price_alert_events', {
+    profileId: input.profileId,
+    since: input.since ?? null,
+    limit: input.limit ?? null,
+  })
+}
+
+export async function listPriceChannels(): Promise<PriceChannel[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<PriceChannel[]>('list_price_channels')
+}
+
+export interface ProfilePricePreferences {
+  profileId: string
+  priceSourceId: string
+  valuationCurrency: string
+  // Units of valuationCurrency per USD and the date of that rate (null for USD).
+  valuationRate: number
+  valuationRateDate: string | null
+  // No rate for valuationCurrency is stored yet; amounts stay in USD until sync_fx_rates runs.
+  valuationRateMissing: boolean
+  moneyLocale: string
+  displayLocale: string
+  defaultPurchaseCurrency: string
+}
+
+export async function getProfilePricePreferences(
+  profileId: string,
+): Promise<ProfilePricePreferences | null> {
+  if (!hasTauriRuntime()) {
+    return null
+  }
+  return invoke<ProfilePricePreferences>('get_profile_price_preferences', { profileId })
+}
+
+export async function setProfilePricePreferences(input: {
+  profileId: string
+  priceSourceId?: string
+  valuationCurrency?: string
+  moneyLocale?: string
+  displayLocale?: string
+  defaultPurchaseCurrency?: string
+}): Promise<ProfilePricePreferences | null> {
+  if (!hasTauriRuntime()) {
+    return null
+  }
+  return invoke<ProfilePricePreferences>('set_profile_price_preferences', { input })
+}
+
 export async function getCollectionPriceTrendsBySource(input: {
   profileId: string
-  sourceId: 'tcg-low' | 'tcg-market' | 'tcg-high' | 'ck-sell' | 'ck-buylist'
+  sourceId: string
 }): Promise<MarketTrend[]> {
   if (!hasTauriRuntime()) {
     return []
@@ -559,28 +937,76 @@ export async function syncCkPricesIntoCardData(): Promise<{
   upsertedBuylist: number
   upsertedSell: number
   skipped: number
+  malformed: number
+  warnings: string[]
 }> {
   if (!hasTauriRuntime()) {
-    return { scanned: 0, upsertedBuylist: 0, upsertedSell: 0, skipped: 0 }
+    return { scanned: 0, upsertedBuylist: 0, upsertedSell: 0, skipped: 0, malformed: 0, warnings: [] }
   }
   return invoke('sync_ck_prices_into_card_data')
 }
 
-export async function syncAllSourcesNow(): Promise<{
-  startedAt: string
-  finishedAt: string
-  syncVersion: string
-  scryfallScanned: number
-  scryfallUpdated: number
-  scryfallUnchanged: number
-  scryfallPriceSnapshots: number
-  tcgSetsScanned: number
-  tcgProductsMatched: number
-  tcgPriceUpserts: number
-  ckScanned: number
-  ckUpsertedBuylist: number
-  ckUpsertedSell: number
-}> {
+// Queues the full source sync. While one is queued or running, that job is returned.
+export async function startFullSourceSync(): Promise<SyncJob> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Source sync requires the desktop app.')
+  }
+  return invoke('sync_all_sources_now')
+}
+
+// Newest first; the backend keeps the last 200 finished jobs.
+export async function listJobs(limit?: number): Promise<Job[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke('list_jobs', { limit: limit ?? null })
+}
+
+export async function getJob(jobId: string): Promise<Job> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Background jobs require the desktop app.')
+  }
+  return invoke('get_job', { jobId })
+}
+
+// Polls the newest queued or running job of `kind` (e.g. the hydration an import queued) until
+// it ends. Resolves with the finished job, or null when no such job is active.
+export async function followActiveJob(
+  kind: JobKind,
+  onProgress?: (job: Job) => void,
+): Promise<Job | null> {
+  if (!hasTauriRuntime()) {
+    return null
+  }
+  const active = (await listJobs(10)).find(
+    (job) => job.kind === kind && (job.status === 'queued' || job.status === 'running'),
+  )
+  if (!active) {
+    return null
+  }
+  let job = active
+  while (job.status === 'queued' || job.status === 'running') {
+    onProgress?.(job)
+    await new Promise((resolve) => window.setTimeout(resolve, SYNC_JOB_POLL_MS))
+    job = await getJob(job.jobId)
+  }
+  onProgress?.(job)
+  return job
+}
+
+// Queued jobs are cancelled at once; a running full sync stops at its next checkpoint.
+export async function cancelJob(jobId: string): Promise<Job> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Background jobs require the desktop app.')
+  }
+  return invoke('cancel_job', { jobId })
+}
+
+// Runs the full source sync job to completion, polling its status. Aborting `signal` cancels the job.
+export async function syncAllSourcesNow(options?: {
+  signal?: AbortSignal
+  onProgress?: (job: SyncJob) => void
+}): Promise<FullSourceSyncResult> {
   if (!hasTauriRuntime()) {
     return {
       startedAt: nowIso(),
@@ -593,17 +1019,39 @@ export async function syncAllSourcesNow(): Promise<{
       tcgSetsScanned: 0,
       tcgProductsMatched: 0,
       tcgPriceUpserts: 0,
+      tcgSetsFailed: 0,
+      tcgMalformedRecords: 0,
       ckScanned: 0,
       ckUpsertedBuylist: 0,
       ckUpsertedSell: 0,
+      ckMalformedRecords: 0,
+      scryfallSetsSynced: 0,
+      newPricedSets: 0,
+      syncWarnings: [],
     }
   }
-  return invoke('sync_all_sources_now')
+  let job = await startFullSourceSync()
+  let cancelSent = false
+  while (job.status === 'queued' || job.status === 'running') {
+    if (options?.signal?.aborted && !cancelSent) {
+      cancelSent = true
+      job = (await cancelJob(job.jobId)) as SyncJob
+    }
+    options?.onProgress?.(job)
+    await new Promise((resolve) => window.setTimeout(resolve, SYNC_JOB_POLL_MS))
+    job = (await getJob(job.jobId)) as SyncJob
+  }
+  options?.onProgress?.(job)
+  if (job.status !== 'completed' || !job.result) {
+    throw new Error(job.error ?? `Sync ${job.status}.`)
+  }
+  return job.result
 }
 
 export async function importCollectionRows(input: {
   profileId: string
   rows: CollectionImportRow[]
+  duplicatePolicy?: ImportDuplicatePolicy
 }): Promise<OwnedCard[]> {
   if (!input.rows.length) {
     return getCollection(input.profileId)
@@ -614,14 +1062,103 @@ export async function importCollectionRows(input: {
   return invoke<OwnedCard[]>('import_collection_rows', { input })
 }
 
-export async function bulkUpdateTags(input: BulkTagRequest): Promise<OwnedCard[]> {
-  if (!input.scryfallIds.length) {
-    return getCollection(input.profileId)
+// Reads the file on the backend; the wizard's row preview still uses the TS importers.
+export async function importCollectionCsv(input: {
+  profileId: string
+  filePath: string
+  format: CsvImportFormat
+  duplicatePolicy?: ImportDuplicatePolicy
+}): Promise<CsvImportSummary> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Importing CSV files from disk requires the desktop app.')
+  }
+  return invoke<CsvImportSummary>('import_collection_csv', { input })
+}
+
+// Reads a JSON collection export of any schema version this build supports.
+export async function importCollectionJson(input: {
+  profileId: string
+  filePath: string
+  duplicatePolicy?: ImportDuplicatePolicy
+}): Promise<CsvImportSummary> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Importing JSON collection files requires the desktop app.')
+  }
+  return invoke<CsvImportSummary>('import_collection_json', { input })
+}
+
+// MTGO client exports or Arena tracker CSVs; each import replaces that platform's holdings.
+export async function importDigitalCollectionCsv(input: {
+  profileId: string
+  filePath: string
+  platform: DigitalPlatform
+}): Promise<DigitalImportSummary> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Importing digital collections requires the desktop app.')
+  }
+  return invoke<DigitalImportSummary>('import_digital_collection_csv', { input })
+}
+
+export async function listDigitalCollection(
+  profileId: string,
+  platform?: DigitalPlatform,
+): Promise<DigitalItem[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<DigitalItem[]>('list_digital_collection', { profileId, platform: platform ?? null })
+}
+
+export async function clearDigitalCollection(profileId: string, platform: DigitalPlatform): Promise<number> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Digital collections require the desktop app.')
+  }
+  return invoke<number>('clear_digital_collection', { profileId, platform })
+}
+
+export async function getDigitalPaperReport(
+  profileId: string,
+  platform?: DigitalPlatform,
+): Promise<DigitalPaperReport> {
+  if (!hasTauriRuntime()) {
+    throw new Error('The digital vs paper report requires the desktop app.')
+  }
+  return invoke<DigitalPaperReport>('get_digital_paper_report', { profileId, platform: platform ?? null })
+}
+
+export async function exportCollection(input: {
+  profileId: string
+  format: CollectionExportFormat
+  dialect?: CollectionExportDialect
+  destinationPath: string
+}): Promise<CollectionExport> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Exporting the collection to disk requires the desktop app.')
+  }
+  return invoke<CollectionExport>('export_collection', { input })
+}
+
+export async function detectImportFormat(filePath: string): Promise<ImportFormatDetection> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Detecting CSV formats requires the desktop app.')
+  }
+  return invoke<ImportFormatDetection>('detect_import_format', { filePath })
+}
+
+export async function bulkUpdateTags(input: BulkTagRequest): Promise<CollectionMutationResult> {
+  if (!input.scryfallIds.length && !input.ownedItemIds?.length && !input.filterQuery?.trim()) {
+    return { cards: await getCollection(input.profileId), affectedOwnedItemIds: [] }
   }
   if (!hasTauriRuntime()) {
-    return fallbackBulkUpdateTags(input)
+    return fallbackMutationResult(await fallbackBulkUpdateTags(input), input.scryfallIds)
   }
-  return invoke<OwnedCard[]>('bulk_update_tags', { input })
+  return invoke<CollectionMutationResult>('bulk_update_tags', { input })
+}
+
+export async function bulkRemoveTags(
+  input: Omit<BulkTagRequest, 'mode'>,
+): Promise<CollectionMutationResult> {
+  return bulkUpdateTags({ ...input, mode: 'remove' })
 }
 
 export async function updateOwnedCardMetadata(
@@ -633,6 +1170,144 @@ export async function updateOwnedCardMetadata(
   return invoke<OwnedCard[]>('update_owned_card_metadata', { input })
 }
 
+export async function bulkUpdateOwnedCardMetadata(
+  input: BulkUpdateOwnedCardMetadataInput,
+): Promise<OwnedCard[]> {
+  if (!input.scryfallIds.length && !input.ownedItemIds?.length && !input.filterQuery?.trim()) {
+    return getCollection(input.profileId)
+  }
+  if (!hasTauriRuntime()) {
+    for (const scryfallId of input.scryfallIds) {
+      await fallbackUpdateOwnedCardMetadata({ ...input, scryfallId })
+    }
+    return fallbackGetCollection(input.profileId)
+  }
+  return invoke<OwnedCard[]>('bulk_update_owned_card_metadata', { input })
+}
+
+export async function bulkMoveToLocation(input: BulkMoveToLocationInput): Promise<OwnedCard[]> {
+  if (!input.scryfallIds.length && !input.ownedItemIds?.length && !input.filterQuery?.trim()) {
+    return getCollection(input.profileId)
+  }
+  if (!hasTauriRuntime()) {
+    return bulkUpdateOwnedCardMetadata({ ...input, locationName: input.locationName ?? '' })
+  }
+  return invoke<OwnedCard[]>('bulk_move_to_location', { input })
+}
+
+export async function listLocations(profileId: string): Promise<Location[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<Location[]>('list_locations', { profileId })
+}
+
+export async function createLocation(input: {
+  profileId: string
+  name: string
+  kind?: string
+}): Promise<Location> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Locations require the desktop app.')
+  }
+  return invoke<Location>('create_location', { input })
+}
+
+// Fails when another location has the name; merge the two instead.
+export async function renameLocation(input: {
+  profileId: string
+  locationId: string
+  name: string
+  kind?: string
+}): Promise<Location> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Locations require the desktop app.')
+  }
+  return invoke<Location>('rename_location', { input })
+}
+
+// Pass null to remove the cap; get_insurance_report warns about locations worth more than theirs.
+export async function setLocationInsuredValueCap(
+  profileId: string,
+  locationId: string,
+  insuredValueCap: number | null,
+): Promise<Location> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Locations require the desktop app.')
+  }
+  return invoke<Location>('set_location_insured_value_cap', { input: { profileId, locationId, insuredValueCap } })
+}
+
+// Moves the sources' cards into the target and deletes the sources; returns the remaining locations.
+export async function mergeLocations(input: {
+  profileId: string
+  sourceLocationIds: string[]
+  targetLocationId: string
+}): Promise<Location[]> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Locations require the desktop app.')
+  }
+  return invoke<Location[]>('merge_locations', { input })
+}
+
+// Cards move to reassignTo, or lose their location when it is omitted.
+export async function deleteLocation(input: {
+  profileId: string
+  locationId: string
+  reassignTo?: string | null
+}): Promise<Location[]> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Locations require the desktop app.')
+  }
+  return invoke<Location[]>('delete_location', { input })
+}
+
+export async function listTags(profileId: string): Promise<Tag[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<Tag[]>('list_tags', { profileId })
+}
+
+// Fails when another tag has the name; merge the two instead.
+export async function renameTag(input: { profileId: string; tagId: string; name: string }): Promise<Tag> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Tag management requires the desktop app.')
+  }
+  return invoke<Tag>('rename_tag', { input })
+}
+
+// colorHex is #rrggbb or #rgb; null clears it.
+export async function setTagColor(input: {
+  profileId: string
+  tagId: string
+  colorHex: string | null
+}): Promise<Tag> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Tag management requires the desktop app.')
+  }
+  return invoke<Tag>('set_tag_color', { input })
+}
+
+// Tags every row of the sources with the target and deletes the sources; returns the remaining tags.
+export async function mergeTags(input: {
+  profileId: string
+  sourceTagIds: string[]
+  targetTagId: string
+}): Promise<Tag[]> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Tag management requires the desktop app.')
+  }
+  return invoke<Tag[]>('merge_tags', { input })
+}
+
+export async function deleteTag(input: { profileId: string; tagId: string }): Promise<Tag[]> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Tag management requires the desktop app.')
+  }
+  return invoke<Tag[]>('delete_tag', { input })
+}
+
 export async function setOwnedCardState(input: {
   profileId: string
   card: OwnedCard
@@ -666,6 +1341,7 @@ function fallbackFilterTokens(query: string, limit = 30): FilterToken[] {
     ['cond:', 'Condition (example: cond:nm)', 'internal'],
     ['is:foil', 'Foil printings', 'scryfall'],
     ['is:nonfoil', 'Nonfoil printings', 'scryfall'],
+    ['is:digital', 'Digital-only printings (Arena, MTGO)', 'scryfall'],
   ] as const
 
   seed.forEach(([token, label, kind], index) => {
@@ -726,23 +1402,1083 @@ export async function syncFilterTokens(profileId: string): Promise<number> {
 export async function getFilterTokens(
   query: string,
   limit = 30,
+  locale?: string,
 ): Promise<FilterToken[]> {
   if (!hasTauriRuntime()) {
     return fallbackFilterTokens(query, limit)
   }
   return invoke<FilterToken[]>('get_filter_tokens', {
-    input: { query, limit },
+    input: { query, limit, locale },
   })
 }
 
-export async function hydrateProfileCardMetadata(input: {
-  profileId: string
-  maxCards?: number
-}): Promise<HydrateProfileCardMetadataResult> {
+export interface LabelCatalog {
+  locale: string
+  language: string
+  labels: Record<string, string>
+}
+
+export async function getLabelCatalog(locale?: string): Promise<LabelCatalog | null> {
   if (!hasTauriRuntime()) {
-    return { attempted: 0, hydrated: 0, remaining: 0 }
+    return null
   }
-  return invoke<HydrateProfileCardMetadataResult>('hydrate_profile_card_metadata', {
-    input,
-  })
+  return invoke<LabelCatalog>('get_label_catalog', { locale })
+}
+
+function requireAliasBackend(): void {
+  if (!hasTauriRuntime()) {
+    throw new Error('Card nicknames require the desktop app.')
+  }
+}
+
+export async function listCardAliases(): Promise<CardAlias[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<CardAlias[]>('list_card_aliases')
+}
+
+export async function saveCardAlias(alias: string, cardName: string): Promise<CardAlias[]> {
+  requireAliasBackend()
+  return invoke<CardAlias[]>('save_card_alias', { input: { alias, cardName } })
+}
+
+export async function deleteCardAlias(alias: string): Promise<CardAlias[]> {
+  requireAliasBackend()
+  return invoke<CardAlias[]>('delete_card_alias', { alias })
+}
+
+export async function listSetAliases(): Promise<SetAlias[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<SetAlias[]>('list_set_aliases')
+}
+
+// Unlike saveSetAlias, refuses an alias that already exists.
+export async function addSetAlias(alias: string, setCode: string): Promise<SetAlias[]> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Set aliases require the desktop app.')
+  }
+  return invoke<SetAlias[]>('add_set_alias', { input: { alias, setCode } })
+}
+
+export async function saveSetAlias(alias: string, setCode: string): Promise<SetAlias[]> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Set aliases require the desktop app.')
+  }
+  return invoke<SetAlias[]>('save_set_alias', { input: { alias, setCode } })
+}
+
+export async function deleteSetAlias(alias: string): Promise<SetAlias[]> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Set aliases require the desktop app.')
+  }
+  return invoke<SetAlias[]>('delete_set_alias', { alias })
+}
+
+export async function resolveCardNames(names: string[]): Promise<ResolvedCardName[]> {
+  if (!hasTauriRuntime()) {
+    return names.map((input) => ({ input, name: input.trim(), alias: null }))
+  }
+  return invoke<ResolvedCardName[]>('resolve_card_names', { names })
+}
+
+export async function searchCards(input: {
+  query: string
+  limit?: number
+  profileId?: string
+  // Defaults to the app-wide digital printings setting.
+  includeDigital?: boolean
+}): Promise<CardSearchHit[]> {
+  if (!hasTauriRuntime() || !input.query.trim()) {
+    return []
+  }
+  return invoke<CardSearchHit[]>('search_cards', {
+    query: input.query,
+    limit: input.limit ?? null,
+    profileId: input.profileId ?? null,
+    includeDigital: input.includeDigital ?? null,
+  })
+}
+
+export async function autocompleteCardNames(prefix: string, limit?: number): Promise<CardNameAutocomplete> {
+  if (!hasTauriRuntime() || !prefix.trim()) {
+    return { source: 'local', names: [] }
+  }
+  return invoke<CardNameAutocomplete>('autocomplete_card_names', { prefix, limit: limit ?? null })
+}
+
+export async function hydrateProfileCardMetadata(input: {
+  profileId: string
+  maxCards?: number
+}): Promise<HydrateProfileCardMetadataResult> {
+  if (!hasTauriRuntime()) {
+    return { attempted: 0, hydrated: 0, remaining: 0, imageUrls: [] }
+  }
+  return invoke<HydrateProfileCardMetadataResult>('hydrate_profile_card_metadata', {
+    input,
+  })
+}
+
+function fallbackBackgroundStatus(): BackgroundStatus {
+  return {
+    enabled: false,
+    intervalMinutes: 360,
+    running: false,
+    windowVisible: true,
+    lastRunAt: null,
+    nextRunAt: null,
+    lastError: null,
+    lastAlertCount: 0,
+  }
+}
+
+export async function getBackgroundStatus(): Promise<BackgroundStatus> {
+  if (!hasTauriRuntime()) {
+    return fallbackBackgroundStatus()
+  }
+  return invoke<BackgroundStatus>('get_background_status')
+}
+
+export async function setBackgroundMode(input: {
+  enabled: boolean
+  intervalMinutes?: number
+}): Promise<BackgroundStatus> {
+  if (!hasTauriRuntime()) {
+    return fallbackBackgroundStatus()
+  }
+  return invoke<BackgroundStatus>('set_background_mode', { input })
+}
+
+export async function getPerformanceMetrics(input?: {
+  metricName?: string
+  days?: number
+  slowLimit?: number
+}): Promise<PerformanceMetrics | null> {
+  if (!hasTauriRuntime()) {
+    return null
+  }
+  return invoke<PerformanceMetrics>('get_performance_metrics', { input: input ?? null })
+}
+
+export async function getOsNotificationSettings(): Promise<OsNotificationSettings> {
+  if (!hasTauriRuntime()) {
+    return { alerts: false, syncComplete: false, jobFailed: false }
+  }
+  return invoke<OsNotificationSettings>('get_os_notification_settings')
+}
+
+export async function setOsNotificationSettings(
+  input: Partial<OsNotificationSettings>,
+): Promise<OsNotificationSettings> {
+  if (!hasTauriRuntime()) {
+    return { alerts: false, syncComplete: false, jobFailed: false }
+  }
+  return invoke<OsNotificationSettings>('set_os_notification_settings', { input })
+}
+
+export async function getPayloadArchiveSettings(): Promise<PayloadArchiveSettings> {
+  if (!hasTauriRuntime()) {
+    return { enabled: false, keep: 5, archiveDir: '' }
+  }
+  return invoke<PayloadArchiveSettings>('get_payload_archive_settings')
+}
+
+export async function setPayloadArchiveSettings(
+  input: Partial<Pick<PayloadArchiveSettings, 'enabled' | 'keep'>>,
+): Promise<PayloadArchiveSettings> {
+  if (!hasTauriRuntime()) {
+    return { enabled: false, keep: 5, archiveDir: '' }
+  }
+  return invoke<PayloadArchiveSettings>('set_payload_archive_settings', { input })
+}
+
+export async function getIncludeDigitalPrintings(): Promise<boolean> {
+  if (!hasTauriRuntime()) {
+    return false
+  }
+  return invoke<boolean>('get_include_digital_printings')
+}
+
+export async function setIncludeDigitalPrintings(enabled: boolean): Promise<boolean> {
+  if (!hasTauriRuntime()) {
+    return enabled
+  }
+  return invoke<boolean>('set_include_digital_printings', { enabled })
+}
+
+export async function getAutoHydrateAfterImport(): Promise<boolean> {
+  if (!hasTauriRuntime()) {
+    return false
+  }
+  return invoke<boolean>('get_auto_hydrate_after_import')
+}
+
+export async function setAutoHydrateAfterImport(enabled: boolean): Promise<boolean> {
+  if (!hasTauriRuntime()) {
+    return enabled
+  }
+  return invoke<boolean>('set_auto_hydrate_after_import', { enabled })
+}
+
+export async function getImagePrefetchStatus(): Promise<ImagePrefetchStatus> {
+  if (!hasTauriRuntime()) {
+    return { queued: 0, cached: 0, failed: 0, cachedBytes: 0, whenIdle: false, job: null }
+  }
+  return invoke<ImagePrefetchStatus>('get_image_prefetch_status')
+}
+
+// Queues card images for the on-disk cache. They download when the job queue is idle, or right
+// away with startNow.
+export async function queueImagePrefetch(input: {
+  profileId?: string
+  scryfallIds?: string[]
+  retryFailed?: boolean
+  startNow?: boolean
+}): Promise<ImagePrefetchStatus> {
+  if (!hasTauriRuntime()) {
+    return { queued: 0, cached: 0, failed: 0, cachedBytes: 0, whenIdle: false, job: null }
+  }
+  return invoke<ImagePrefetchStatus>('queue_image_prefetch', { input })
+}
+
+export async function setImagePrefetchWhenIdle(enabled: boolean): Promise<boolean> {
+  if (!hasTauriRuntime()) {
+    return enabled
+  }
+  return invoke<boolean>('set_image_prefetch_when_idle', { enabled })
+}
+
+export async function getDashboardFeedSettings(): Promise<DashboardFeedStatus> {
+  if (!hasTauriRuntime()) {
+    return {
+      enabled: false,
+      port: 8765,
+      allowLan: false,
+      token: null,
+      running: false,
+      address: null,
+      lastError: null,
+    }
+  }
+  return invoke<DashboardFeedStatus>('get_dashboard_feed_settings')
+}
+
+// Saves the settings and restarts the feed; a port that can't be opened shows up in lastError.
+export async function setDashboardFeedSettings(input: {
+  enabled: boolean
+  port?: number
+  allowLan?: boolean
+  regenerateToken?: boolean
+}): Promise<DashboardFeedStatus> {
+  if (!hasTauriRuntime()) {
+    throw new Error('The dashboard feed requires the desktop app.')
+  }
+  return invoke<DashboardFeedStatus>('set_dashboard_feed_settings', { input })
+}
+
+// Scryfall searches return Arena and MTGO printings too; leave them out unless they are included
+// or the query already says which game it wants.
+export function scryfallQueryForPrintings(query: string, includeDigital: boolean): string {
+  if (includeDigital || /\b((is|not):digital|game:|in:)/i.test(query)) {
+    return query
+  }
+  return `${query} -is:digital`
+}
+
+export async function listArchivedPayloads(source?: string): Promise<ArchivedPayload[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<ArchivedPayload[]>('list_archived_payloads', { source: source ?? null })
+}
+
+export async function wakeMainWindow(): Promise<void> {
+  if (!hasTauriRuntime()) {
+    return
+  }
+  await invoke('wake_main_window')
+}
+
+export async function getAppDataLocation(): Promise<AppDataLocation | null> {
+  if (!hasTauriRuntime()) {
+    return null
+  }
+  return invoke<AppDataLocation>('get_app_data_location')
+}
+
+// Pass portable: true to move next to the executable; otherwise newPath is required.
+export async function moveAppData(input: {
+  newPath?: string
+  portable?: boolean
+}): Promise<AppDataLocation> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Moving app data requires the desktop app.')
+  }
+  return invoke<AppDataLocation>('move_app_data', input)
+}
+
+export async function listRecentWorkspaces(): Promise<Workspace[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<Workspace[]>('list_recent_workspaces')
+}
+
+// Omit path to return to the default workspace; create starts a new database file.
+export async function openWorkspace(input: {
+  path?: string
+  name?: string
+  create?: boolean
+}): Promise<Workspace> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Workspaces require the desktop app.')
+  }
+  return invoke<Workspace>('open_workspace', { input })
+}
+
+function requireRetailBackend(): void {
+  if (!hasTauriRuntime()) {
+    throw new Error('Retail mode requires the desktop app.')
+  }
+}
+
+export async function getRetailMode(profileId: string): Promise<boolean> {
+  if (!hasTauriRuntime()) {
+    return false
+  }
+  return invoke<boolean>('get_retail_mode', { profileId })
+}
+
+export async function setRetailMode(profileId: string, enabled: boolean): Promise<boolean> {
+  requireRetailBackend()
+  return invoke<boolean>('set_retail_mode', { input: { profileId, enabled } })
+}
+
+// When on, rows whose last copy is sold or removed stay as previously owned records.
+export async function getZeroQuantityRetention(profileId: string): Promise<boolean> {
+  if (!hasTauriRuntime()) {
+    return false
+  }
+  return invoke<boolean>('get_zero_quantity_retention', { profileId })
+}
+
+export async function setZeroQuantityRetention(profileId: string, enabled: boolean): Promise<boolean> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Keeping previously owned cards requires the desktop app.')
+  }
+  return invoke<boolean>('set_zero_quantity_retention', { input: { profileId, enabled } })
+}
+
+export async function listPreviouslyOwnedCards(profileId: string): Promise<PreviouslyOwnedCard[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<PreviouslyOwnedCard[]>('list_previously_owned_cards', { profileId })
+}
+
+// Printings among scryfallIds with no owned copies now but a kept row or a recorded sale.
+export async function getPreviousOwnership(
+  profileId: string,
+  scryfallIds: string[],
+): Promise<PreviousOwnership[]> {
+  if (!hasTauriRuntime() || scryfallIds.length === 0) {
+    return []
+  }
+  return invoke<PreviousOwnership[]>('get_previous_ownership', { profileId, scryfallIds })
+}
+
+export async function setAskingPrice(input: {
+  profileId: string
+  ownedItemIds: string[]
+  askingPrice: number | null
+}): Promise<CollectionMutationResult> {
+  requireRetailBackend()
+  return invoke<CollectionMutationResult>('set_asking_price', { input })
+}
+
+export async function setTradeStatus(input: {
+  profileId: string
+  ownedItemIds?: string[]
+  filterQuery?: string
+  tradeStatus: TradeStatus | null
+}): Promise<CollectionMutationResult> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Trade designations require the desktop app.')
+  }
+  return invoke<CollectionMutationResult>('set_trade_status', { input })
+}
+
+export async function reserveCards(input: {
+  profileId: string
+  ownedItemId: string
+  customerName: string
+  quantity?: number
+  foilQuantity?: number
+  note?: string
+}): Promise<Reservation> {
+  requireRetailBackend()
+  return invoke<Reservation>('reserve_cards', { input })
+}
+
+export async function releaseReservation(
+  profileId: string,
+  reservationId: string,
+): Promise<Reservation[]> {
+  requireRetailBackend()
+  return invoke<Reservation[]>('release_reservation', { input: { profileId, reservationId } })
+}
+
+export async function listReservations(
+  profileId: string,
+  status: Reservation['status'] | 'all' = 'active',
+): Promise<Reservation[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<Reservation[]>('list_reservations', { input: { profileId, status } })
+}
+
+export async function recordSale(input: {
+  profileId: string
+  customerName?: string
+  note?: string
+  lines: SaleLineInput[]
+}): Promise<Sale> {
+  requireRetailBackend()
+  return invoke<Sale>('record_sale', { input })
+}
+
+export async function getSalesReport(input: {
+  profileId: string
+  since?: string
+  until?: string
+}): Promise<SalesReport> {
+  requireRetailBackend()
+  return invoke<SalesReport>('get_sales_report', { input })
+}
+
+export async function getProfitLossReport(profileId: string): Promise<ProfitLossReport> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Profit and loss reports require the desktop app.')
+  }
+  return invoke<ProfitLossReport>('get_profit_loss_report', { profileId })
+}
+
+export async function getPortfolioReport(profileId: string): Promise<PortfolioReport> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Portfolio reports require the desktop app.')
+  }
+  return invoke<PortfolioReport>('get_portfolio_report', { profileId })
+}
+
+export async function syncFxRates(): Promise<FxRateSyncResult> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Exchange rate sync requires the desktop app.')
+  }
+  return invoke<FxRateSyncResult>('sync_fx_rates')
+}
+
+export async function syncDemandRanks(): Promise<DemandSyncResult> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Demand rank sync requires the desktop app.')
+  }
+  return invoke<DemandSyncResult>('sync_demand_ranks')
+}
+
+export async function getPricingRules(profileId: string): Promise<PricingRules> {
+  if (!hasTauriRuntime()) {
+    return { asking: {}, trade: {} }
+  }
+  return invoke<PricingRules>('get_pricing_rules', { profileId })
+}
+
+export async function setPricingRules(profileId: string, rules: PricingRules): Promise<PricingRules> {
+  if (!hasTauriRuntime()) {
+    return rules
+  }
+  return invoke<PricingRules>('set_pricing_rules', { input: { profileId, rules } })
+}
+
+export async function getConditionMultipliers(profileId: string): Promise<ConditionMultipliers> {
+  if (!hasTauriRuntime()) {
+    return { multipliers: { NM: 1, LP: 0.85, MP: 0.7, HP: 0.5, DMG: 0.3 } }
+  }
+  return invoke<ConditionMultipliers>('get_condition_multipliers', { profileId })
+}
+
+// Codes left out of `multipliers` keep their defaults.
+export async function setConditionMultipliers(
+  profileId: string,
+  multipliers: Record<string, number>,
+): Promise<ConditionMultipliers> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Condition multipliers require the desktop app.')
+  }
+  return invoke<ConditionMultipliers>('set_condition_multipliers', { input: { profileId, multipliers } })
+}
+
+export async function getLanguagePremiums(profileId: string): Promise<LanguagePremiums> {
+  if (!hasTauriRuntime()) {
+    return { nonfoil: { jp: 1.1, ko: 1, ru: 1 }, foil: { jp: 1.5, ko: 1.2, ru: 1.2 } }
+  }
+  return invoke<LanguagePremiums>('get_language_premiums', { profileId })
+}
+
+// Languages left out of either table keep their defaults.
+export async function setLanguagePremiums(
+  profileId: string,
+  premiums: Partial<LanguagePremiums>,
+): Promise<LanguagePremiums> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Language premiums require the desktop app.')
+  }
+  return invoke<LanguagePremiums>('set_language_premiums', { input: { profileId, ...premiums } })
+}
+
+// Pass rules to preview unsaved changes; omit ownedItemIds / filterQuery for the whole collection.
+export async function previewPricingRules(input: {
+  profileId: string
+  rules?: PricingRules
+  ownedItemIds?: string[]
+  filterQuery?: string
+  tradeScope?: TradeScope
+  limit?: number
+}): Promise<PricingPreview[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<PricingPreview[]>('preview_pricing_rules', { input })
+}
+
+export async function exportInventoryFeed(input: InventoryFeedRequest): Promise<InventoryFeed> {
+  requireRetailBackend()
+  return invoke<InventoryFeed>('export_inventory_feed', { input })
+}
+
+export async function generateSaleListing(
+  profileId: string,
+  filter?: string,
+  template?: SaleListingTemplate,
+): Promise<SaleListing> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Sale listings require the desktop app.')
+  }
+  return invoke<SaleListing>('generate_sale_listing', {
+    profileId,
+    filter: filter ?? null,
+    template: template ?? null,
+  })
+}
+
+function requireExportTemplateBackend(): void {
+  if (!hasTauriRuntime()) {
+    throw new Error('Export templates require the desktop app.')
+  }
+}
+
+export async function listExportTemplates(profileId: string): Promise<ExportTemplate[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<ExportTemplate[]>('list_export_templates', { profileId })
+}
+
+export async function saveExportTemplate(input: SaveExportTemplateInput): Promise<ExportTemplate> {
+  requireExportTemplateBackend()
+  return invoke<ExportTemplate>('save_export_template', { input })
+}
+
+export async function deleteExportTemplate(profileId: string, templateId: string): Promise<ExportTemplate[]> {
+  requireExportTemplateBackend()
+  return invoke<ExportTemplate[]>('delete_export_template', { input: { profileId, templateId } })
+}
+
+export async function renderExportTemplate(
+  profileId: string,
+  templateId: string,
+  filter?: string,
+  outputPath?: string,
+): Promise<RenderedExport> {
+  requireExportTemplateBackend()
+  return invoke<RenderedExport>('render_export_template', {
+    profileId,
+    templateId,
+    filter: filter ?? null,
+    outputPath: outputPath ?? null,
+  })
+}
+
+export async function exportPrintList(
+  profileId: string,
+  filter?: string,
+  options?: PrintListOptions,
+): Promise<PrintList> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Print lists require the desktop app.')
+  }
+  return invoke<PrintList>('export_print_list', {
+    profileId,
+    filter: filter ?? null,
+    options: options ?? null,
+  })
+}
+
+export async function getInventoryFeedSchedule(): Promise<InventoryFeedRequest | null> {
+  if (!hasTauriRuntime()) {
+    return null
+  }
+  return invoke<InventoryFeedRequest | null>('get_inventory_feed_schedule')
+}
+
+// Pass null to stop regenerating the feed after background syncs.
+export async function setInventoryFeedSchedule(
+  feed: InventoryFeedRequest | null,
+): Promise<InventoryFeedRequest | null> {
+  requireRetailBackend()
+  return invoke<InventoryFeedRequest | null>('set_inventory_feed_schedule', { input: { feed } })
+}
+
+function requireKitBackend(): void {
+  if (!hasTauriRuntime()) {
+    throw new Error('Loan-out kits require the desktop app.')
+  }
+}
+
+export async function listKits(profileId: string): Promise<Kit[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<Kit[]>('list_kits', { profileId })
+}
+
+export async function saveKit(input: {
+  profileId: string
+  kitId?: string
+  name: string
+  description?: string
+  items: KitItemInput[]
+}): Promise<Kit> {
+  requireKitBackend()
+  return invoke<Kit>('save_kit', { input })
+}
+
+export async function deleteKit(profileId: string, kitId: string): Promise<Kit[]> {
+  requireKitBackend()
+  return invoke<Kit[]>('delete_kit', { input: { profileId, kitId } })
+}
+
+export async function checkOutKit(input: {
+  profileId: string
+  kitId: string
+  borrowerName: string
+  dueAt?: string
+  note?: string
+}): Promise<Kit> {
+  requireKitBackend()
+  return invoke<Kit>('check_out_kit', { input })
+}
+
+export async function checkInKit(profileId: string, kitId: string): Promise<Kit> {
+  requireKitBackend()
+  return invoke<Kit>('check_in_kit', { input: { profileId, kitId } })
+}
+
+export async function getOutOfHouseReport(profileId: string): Promise<OutOfHouseReport> {
+  requireKitBackend()
+  return invoke<OutOfHouseReport>('get_out_of_house_report', { profileId })
+}
+
+function requireDeckBackend(): void {
+  if (!hasTauriRuntime()) {
+    throw new Error('Decks require the desktop app.')
+  }
+}
+
+export async function listDecks(profileId: string): Promise<Deck[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<Deck[]>('list_decks', { profileId })
+}
+
+export async function getDeck(profileId: string, deckId: string): Promise<Deck> {
+  requireDeckBackend()
+  return invoke<Deck>('get_deck', { input: { profileId, deckId } })
+}
+
+export async function createDeck(input: {
+  profileId: string
+  name: string
+  format?: string
+  description?: string
+}): Promise<Deck> {
+  requireDeckBackend()
+  return invoke<Deck>('create_deck', { input })
+}
+
+export async function renameDeck(profileId: string, deckId: string, name: string): Promise<Deck> {
+  requireDeckBackend()
+  return invoke<Deck>('rename_deck', { input: { profileId, deckId, name } })
+}
+
+export async function deleteDeck(profileId: string, deckId: string): Promise<Deck[]> {
+  requireDeckBackend()
+  return invoke<Deck[]>('delete_deck', { input: { profileId, deckId } })
+}
+
+export async function addDeckCard(input: {
+  profileId: string
+  deckId: string
+  scryfallId: string
+  board?: DeckBoard
+  quantity?: number
+}): Promise<Deck> {
+  requireDeckBackend()
+  return invoke<Deck>('add_deck_card', { input })
+}
+
+// Without a quantity the whole line is removed from the board.
+export async function removeDeckCard(input: {
+  profileId: string
+  deckId: string
+  scryfallId: string
+  board?: DeckBoard
+  quantity?: number
+}): Promise<Deck> {
+  requireDeckBackend()
+  return invoke<Deck>('remove_deck_card', { input })
+}
+
+// Takes a public Moxfield, Archidekt or TappedOut deck link.
+export async function importDeckFromUrl(profileId: string, url: string): Promise<DeckImportResult> {
+  requireDeckBackend()
+  return invoke<DeckImportResult>('import_deck_from_url', { input: { profileId, url } })
+}
+
+// Writes a versioned deck file (docs/FILE_SCHEMAS.md).
+export async function exportDeck(input: {
+  profileId: string
+  deckId: string
+  destinationPath: string
+}): Promise<DeckExport> {
+  requireDeckBackend()
+  return invoke<DeckExport>('export_deck', { input })
+}
+
+export async function importDeckFile(profileId: string, filePath: string): Promise<DeckImportResult> {
+  requireDeckBackend()
+  return invoke<DeckImportResult>('import_deck_file', { input: { profileId, filePath } })
+}
+
+function requireLimitedEventBackend(): void {
+  if (!hasTauriRuntime()) {
+    throw new Error('Limited events require the desktop app.')
+  }
+}
+
+export async function listLimitedEvents(profileId: string): Promise<LimitedEvent[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<LimitedEvent[]>('list_limited_events', { profileId })
+}
+
+export async function saveLimitedEvent(input: {
+  profileId: string
+  eventId?: string
+  format: LimitedEventFormat
+  name?: string
+  setCode?: string
+  eventDate?: string
+  wins?: number
+  losses?: number
+  draws?: number
+  archetype?: string
+  notes?: string
+  cards?: LimitedPoolCardInput[]
+}): Promise<LimitedEvent> {
+  requireLimitedEventBackend()
+  return invoke<LimitedEvent>('save_limited_event', { input })
+}
+
+export async function deleteLimitedEvent(profileId: string, eventId: string): Promise<LimitedEvent[]> {
+  requireLimitedEventBackend()
+  return invoke<LimitedEvent[]>('delete_limited_event', { input: { profileId, eventId } })
+}
+
+export async function mergeLimitedPool(input: {
+  profileId: string
+  eventId: string
+  conditionCode?: string
+  language?: string
+  locationName?: string
+}): Promise<CollectionMutationResult> {
+  requireLimitedEventBackend()
+  return invoke<CollectionMutationResult>('merge_limited_pool', { input })
+}
+
+function requireAuditBackend(): void {
+  if (!hasTauriRuntime()) {
+    throw new Error('Collection audits require the desktop app.')
+  }
+}
+
+export async function listAudits(profileId: string): Promise<Audit[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<Audit[]>('list_audits', { profileId })
+}
+
+export async function startAudit(input: {
+  profileId: string
+  locationName?: string
+  notes?: string
+}): Promise<Audit> {
+  requireAuditBackend()
+  return invoke<Audit>('start_audit', { input })
+}
+
+export async function getAudit(profileId: string, auditId: string): Promise<Audit> {
+  requireAuditBackend()
+  return invoke<Audit>('get_audit', { input: { profileId, auditId } })
+}
+
+export async function deleteAudit(profileId: string, auditId: string): Promise<Audit[]> {
+  requireAuditBackend()
+  return invoke<Audit[]>('delete_audit', { input: { profileId, auditId } })
+}
+
+export async function recordAuditCount(input: AuditCountInput): Promise<Audit> {
+  requireAuditBackend()
+  return invoke<Audit>('record_audit_count', { input })
+}
+
+export async function reconcileAudit(input: {
+  profileId: string
+  auditId: string
+  uncountedAsMissing?: boolean
+}): Promise<CollectionMutationResult> {
+  requireAuditBackend()
+  return invoke<CollectionMutationResult>('reconcile_audit', { input })
+}
+
+function requireAttachmentBackend(): void {
+  if (!hasTauriRuntime()) {
+    throw new Error('Photo attachments require the desktop app.')
+  }
+}
+
+export async function attachOwnedCardPhoto(input: {
+  profileId: string
+  ownedItemId: string
+  sourcePath: string
+  caption?: string
+  primary?: boolean
+}): Promise<CollectionMutationResult> {
+  requireAttachmentBackend()
+  return invoke<CollectionMutationResult>('attach_owned_card_photo', { input })
+}
+
+export async function listOwnedCardAttachments(
+  profileId: string,
+  ownedItemId?: string,
+): Promise<Attachment[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<Attachment[]>('list_owned_card_attachments', { profileId, ownedItemId: ownedItemId ?? null })
+}
+
+export async function setPrimaryCardImage(
+  profileId: string,
+  ownedItemId: string,
+  attachmentId: string | null,
+): Promise<CollectionMutationResult> {
+  requireAttachmentBackend()
+  return invoke<CollectionMutationResult>('set_primary_card_image', {
+    input: { profileId, ownedItemId, attachmentId },
+  })
+}
+
+export async function removeAttachment(
+  profileId: string,
+  attachmentId: string,
+): Promise<CollectionMutationResult> {
+  requireAttachmentBackend()
+  return invoke<CollectionMutationResult>('remove_attachment', { input: { profileId, attachmentId } })
+}
+
+export async function getActivityFeed(
+  profileId: string,
+  page = 0,
+  pageSize?: number,
+): Promise<ActivityFeed> {
+  if (!hasTauriRuntime()) {
+    return { profileId, page, pageSize: pageSize ?? 50, hasMore: false, items: [] }
+  }
+  return invoke<ActivityFeed>('get_activity_feed', { profileId, page, pageSize: pageSize ?? null })
+}
+
+export async function listRecentOracleChanges(profileId: string, days?: number): Promise<OracleChange[]> {
+  if (!hasTauriRuntime()) {
+    return []
+  }
+  return invoke<OracleChange[]>('list_recent_oracle_changes', { profileId, days: days ?? null })
+}
+
+export async function getCollectionTotals(profileId: string): Promise<CollectionTotals> {
+  if (!hasTauriRuntime()) {
+    const cards = await fallbackGetCollection(profileId)
+    const totalValue = cards.reduce(
+      (sum, card) => sum + (card.currentPrice ?? 0) * (card.quantity + card.foilQuantity),
+      0,
+    )
+    const totalValueFormatted = `$${totalValue.toFixed(2)}`
+    return {
+      profileId,
+      currency: 'USD',
+      totalCards: cards.reduce((sum, card) => sum + card.quantity + card.foilQuantity, 0),
+      totalFoils: cards.reduce((sum, card) => sum + card.foilQuantity, 0),
+      rowCount: cards.length,
+      priceSourceId: 'tcg-market',
+      totalValue,
+      totalValueFormatted,
+      values: [{ channelId: 'tcg-market', label: 'TCGplayer Market', totalValue, totalValueFormatted }],
+      sets: [],
+    }
+  }
+  return invoke<CollectionTotals>('get_collection_totals', { profileId })
+}
+
+export async function recordCollectionValueSnapshot(profileId: string): Promise<ValueSnapshot> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Value snapshots require the desktop app.')
+  }
+  return invoke<ValueSnapshot>('record_collection_value_snapshot', { profileId })
+}
+
+export async function getCollectionValueHistory(profileId: string, days?: number): Promise<CollectionValueHistory> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Value history requires the desktop app.')
+  }
+  return invoke<CollectionValueHistory>('get_collection_value_history', { profileId, days: days ?? null })
+}
+
+export async function getValueInUnits(profileId: string, benchmarkScryfallId: string): Promise<ValueInUnits> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Valuing the collection in cards requires the desktop app.')
+  }
+  return invoke<ValueInUnits>('get_value_in_units', { profileId, benchmarkScryfallId })
+}
+
+// `source` is a price channel id (default: the profile price source); `conditionAdjusted` scales
+// each copy by the profile's condition multipliers.
+export async function getCollectionValuation(
+  profileId: string,
+  tradeScope: TradeScope = 'all',
+  options: { source?: string; conditionAdjusted?: boolean } = {},
+): Promise<CollectionValuation> {
+  if (!hasTauriRuntime()) {
+    return {
+      profileId,
+      currency: 'USD',
+      priceSourceId: options.source ?? 'tcg-market',
+      tradeScope,
+      conditionAdjusted: options.conditionAdjusted ?? false,
+      copyCount: 0,
+      unpricedCopyCount: 0,
+      totalValue: 0,
+      totalValueFormatted: '$0.00',
+      byDeck: [],
+      byTag: [],
+      byTradeStatus: [],
+    }
+  }
+  return invoke<CollectionValuation>('get_collection_valuation', {
+    profileId,
+    tradeScope,
+    source: options.source,
+    conditionAdjusted: options.conditionAdjusted,
+  })
+}
+
+export async function getInsuranceReport(
+  profileId: string,
+  mode: InsuranceValuationMode = 'market',
+  tradeScope: TradeScope = 'all',
+): Promise<InsuranceReport> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Insurance reports require the desktop app.')
+  }
+  return invoke<InsuranceReport>('get_insurance_report', { profileId, mode, tradeScope })
+}
+
+export async function suggestTradeFodder(input: TradeFodderRequest): Promise<TradeFodderReport> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Trade suggestions require the desktop app.')
+  }
+  return invoke<TradeFodderReport>('suggest_trade_fodder', { input })
+}
+
+export async function getDemandReport(input: DemandReportRequest): Promise<DemandReport> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Demand reports require the desktop app.')
+  }
+  return invoke<DemandReport>('get_demand_report', { input })
+}
+
+export async function getLanguageReport(input: LanguageReportRequest): Promise<LanguageReport> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Language reports require the desktop app.')
+  }
+  return invoke<LanguageReport>('get_language_report', { input })
+}
+
+export async function planPrintingUpgrades(input: PrintingUpgradeRequest): Promise<PrintingUpgradePlan> {
+  if (!hasTauriRuntime()) {
+    throw new Error('Printing upgrade plans require the desktop app.')
+  }
+  return invoke<PrintingUpgradePlan>('plan_printing_upgrades', { input })
+}
+
+export async function getCollectionFacets(profileId: string, filter?: string): Promise<CollectionFacets> {
+  if (!hasTauriRuntime()) {
+    return {
+      profileId,
+      filter: filter ?? null,
+      rowCount: 0,
+      sets: [],
+      colors: [],
+      rarities: [],
+      types: [],
+      conditions: [],
+      tags: [],
+      locations: [],
+    }
+  }
+  return invoke<CollectionFacets>('get_collection_facets', { profileId, filter: filter ?? null })
+}
+
+function requireComboBackend(): void {
+  if (!hasTauriRuntime()) {
+    throw new Error('Combo search requires the desktop app.')
+  }
+}
+
+export async function syncComboDataset(): Promise<ComboSyncResult> {
+  requireComboBackend()
+  return invoke<ComboSyncResult>('sync_combo_dataset')
+}
+
+export async function findCombosInCollection(profileId: string): Promise<ComboReport> {
+  requireComboBackend()
+  return invoke<ComboReport>('find_combos_in_collection', { profileId })
+}
+
+export async function findCombosInDeck(deckId: string): Promise<ComboReport> {
+  requireComboBackend()
+  return invoke<ComboReport>('find_combos_in_deck', { deckId })
 }
@@ -1,4 +1,5 @@
 import { invoke } from '@tauri-apps/api/core'
+import { decodeCompactRows } from './msgpack'
 
 export interface CatalogPriceRecord {
   scryfallId: string
@@ -7,6 +8,11 @@ export interface CatalogPriceRecord {
   collectorNumber: string
   imageUrl?: string
   marketPrice: number
+  lowPrice?: number | null
+  midPrice?: number | null
+  highPrice?: number | null
+  ckSellPrice?: number | null
+  ckBuylistPrice?: number | null
   updatedAt: string
 }
 
@@ -406,10 +412,12 @@ async function getBackendCatalogPriceRecords(
   if (!scryfallIds.length) {
     return []
   }
-  return invoke<CatalogPriceRecord[]>('get_catalog_price_records', {
+  const payload = await invoke<ArrayBuffer>('get_catalog_price_records', {
     dataset: CATALOG_DATASET,
     scryfallIds,
+    encoding: 'msgpack',
   })
+  return decodeCompactRows<CatalogPriceRecord>(payload)
 }
 
 async function applyBackendSnapshot(input: {
@@ -1,4 +1,4 @@
-import type { CollectionImportRow } from '../../types'
+import type { CollectionImportRow, ImportDuplicateWarning } from '../../types'
 
 export type DelimiterMode = 'auto' | 'comma' | 'tab' | 'semicolon' | 'pipe' | 'custom'
 
@@ -14,6 +14,7 @@ export type ImportFieldKey =
   | 'condition'
   | 'language'
   | 'purchasePrice'
+  | 'purchaseCurrency'
   | 'dateAdded'
   | 'typeLine'
   | 'manaValue'
@@ -40,6 +41,7 @@ export const IMPORT_FIELD_DEFINITIONS: ImportFieldDefinition[] = [
   { key: 'condition', label: 'Condition' },
   { key: 'language', label: 'Language' },
   { key: 'purchasePrice', label: 'Purchase Price' },
+  { key: 'purchaseCurrency', label: 'Purchase Currency' },
   { key: 'dateAdded', label: 'Date Added' },
   { key: 'typeLine', label: 'Card Types / Type Line' },
   { key: 'manaValue', label: 'Mana Value' },
@@ -61,6 +63,7 @@ export interface DelimitedImportMapping {
   condition: number | null
   language: number | null
   purchasePrice: number | null
+  purchaseCurrency: number | null
   dateAdded: number | null
   typeLine: number | null
   manaValue: number | null
@@ -90,7 +93,9 @@ export interface DelimitedTransformResult {
     reason: string
     preview: string
   }>
+  // Keyed by importRowAggregateKey.
   sourceRowNumbersByKey: Record<string, number[]>
+  duplicateWarnings: ImportDuplicateWarning[]
 }
 
 const UUID_PATTERN =
@@ -226,6 +231,7 @@ export function buildDefaultMapping(headers: string[]): DelimitedImportMapping {
     condition: first('condition'),
     language: first('language', 'lang'),
     purchasePrice: first('purchase price', 'purchase_price', 'cost basis', 'cost'),
+    purchaseCurrency: first('purchase currency', 'purchase price currency', 'purchase_currency', 'currency'),
     dateAdded: first('date added', 'date_added', 'acquired', 'acquired at'),
     typeLine: first('card types', 'type line', 'type'),
     manaValue: first('mana value', 'cmc'),
@@ -303,6 +309,41 @@ function looksLikeScryfallId(value: string): boolean {
   return UUID_PATTERN.test(value.trim())
 }
 
+function importRowIdentity(row: CollectionImportRow): string {
+  return looksLikeScryfallId(row.scryfallId)
+    ? row.scryfallId.trim().toLowerCase()
+    : `${row.setCode}|${row.collectorNumber}|${row.name.toLowerCase()}`
+}
+
+// Rows with the same card, condition, language and location land on the same owned row, as in the backend.
+export function importRowAggregateKey(row: CollectionImportRow): string {
+  return [
+    importRowIdentity(row),
+    (row.conditionCode || 'NM').toUpperCase(),
+    (row.language || 'en').toLowerCase(),
+    (row.locationName ?? '').trim().toLowerCase(),
+  ].join('|')
+}
+
+function importRowConflicts(first: CollectionImportRow, next: CollectionImportRow): string[] {
+  const differs = (a: string | number | null | undefined, b: string | number | null | undefined) =>
+    a !== null && a !== undefined && a !== '' && b !== null && b !== undefined && b !== '' && a !== b
+  const conflicts: string[] = []
+  if (differs(first.purchasePrice, next.purchasePrice)) {
+    conflicts.push('purchasePrice')
+  }
+  if (differs(first.purchaseCurrency, next.purchaseCurrency)) {
+    conflicts.push('purchaseCurrency')
+  }
+  if (differs(first.dateAdded, next.dateAdded)) {
+    conflicts.push('dateAdded')
+  }
+  if (differs(first.notes, next.notes)) {
+    conflicts.push('notes')
+  }
+  return conflicts
+}
+
 export function transformDelimitedRowsToImport(
   parseResult: DelimitedImportParseResult,
   mapping: DelimitedImportMapping,
@@ -310,6 +351,7 @@ export function transformDelimitedRowsToImport(
 ): DelimitedTransformResult {
   const aggregate = new Map<string, CollectionImportRow>()
   const sourceRowNumbersByKey = new Map<string, number[]>()
+  const conflictsByKey = new Map<string, Set<string>>()
   let rowsImported = 0
   let copiesImported = 0
   let rowsSkipped = 0
@@ -339,6 +381,7 @@ export function transformDelimitedRowsToImport(
     const conditionCode = pick(row, mapping.condition).trim().toUpperCase()
     const language = pick(row, mapping.language).trim().toLowerCase()
     const purchasePrice = parseNumberOrNull(pick(row, mapping.purchasePrice))
+    const purchaseCurrency = pick(row, mapping.purchaseCurrency).trim().toUpperCase()
     const dateAdded = pick(row, mapping.dateAdded).trim()
     const typeLine = pick(row, mapping.typeLine).trim()
     const manaValue = parseNumberOrNull(pick(row, mapping.manaValue))
@@ -362,42 +405,42 @@ export function transformDelimitedRowsToImport(
       continue
     }
 
-    const aggregateKey = hasValidScryfallId
-      ? scryfallId
-      : `${setCode}|${collectorNumber}|${name.toLowerCase()}`
+    const next: CollectionImportRow = {
+      scryfallId,
+      name,
+      setCode,
+      collectorNumber,
+      imageUrl: imageUrl || null,
+      typeLine: typeLine || null,
+      colorIdentity: colorIdentity.length ? colorIdentity : [],
+      manaValue: manaValue ?? null,
+      rarity: rarity || null,
+      quantity: foil ? 0 : quantity,
+      foilQuantity: foil ? quantity : 0,
+      tags,
+      locationName: locationName || null,
+      conditionCode: conditionCode || 'NM',
+      language: language || 'en',
+      notes: notes || null,
+      purchasePrice,
+      purchaseCurrency: purchasePrice !== null ? purchaseCurrency || null : null,
+      dateAdded: dateAdded || null,
+    }
+    const aggregateKey = importRowAggregateKey(next)
     if (!sourceRowNumbersByKey.has(aggregateKey)) {
       sourceRowNumbersByKey.set(aggregateKey, [])
     }
     sourceRowNumbersByKey.get(aggregateKey)?.push(rowNumber)
     const existing = aggregate.get(aggregateKey)
     if (!existing) {
-      aggregate.set(aggregateKey, {
-        scryfallId,
-        name,
-        setCode,
-        collectorNumber,
-        imageUrl: imageUrl || null,
-        typeLine: typeLine || null,
-        colorIdentity: colorIdentity.length ? colorIdentity : [],
-        manaValue: manaValue ?? null,
-        rarity: rarity || null,
-        quantity: foil ? 0 : quantity,
-        foilQuantity: foil ? quantity : 0,
-        tags,
-        locationName: locationName || null,
-        conditionCode: conditionCode || 'NM',
-        language: language || 'en',
-        notes: notes || null,
-        purchasePrice,
-        dateAdded: dateAdded || null,
-      })
+      aggregate.set(aggregateKey, next)
     } else {
+      const conflicts = conflictsByKey.get(aggregateKey) ?? new Set<string>()
+      importRowConflicts(existing, next).forEach((field) => conflicts.add(field))
+      conflictsByKey.set(aggregateKey, conflicts)
       existing.quantity += foil ? 0 : quantity
       existing.foilQuantity += foil ? quantity : 0
       existing.tags = [...new Set([...(existing.tags ?? []), ...tags])]
-      if (!existing.locationName && locationName) {
-        existing.locationName = locationName
-      }
       if ((!existing.typeLine || !existing.typeLine.trim()) && typeLine) {
         existing.typeLine = typeLine
       }
@@ -412,6 +455,7 @@ export function transformDelimitedRowsToImport(
       }
       if ((existing.purchasePrice ?? null) === null && purchasePrice !== null) {
         existing.purchasePrice = purchasePrice
+        existing.purchaseCurrency = purchaseCurrency || null
       }
       if (!existing.dateAdded && dateAdded) {
         existing.dateAdded = dateAdded
@@ -425,6 +469,38 @@ export function transformDelimitedRowsToImport(
     copiesImported += quantity
   }
 
+  const duplicateWarnings: ImportDuplicateWarning[] = []
+  const rowNumbersByIdentity = new Map<string, { row: CollectionImportRow; keys: number; rowNumbers: number[] }>()
+  for (const [key, row] of aggregate.entries()) {
+    const rowNumbers = sourceRowNumbersByKey.get(key) ?? []
+    if (rowNumbers.length > 1) {
+      duplicateWarnings.push({
+        scryfallId: row.scryfallId,
+        name: row.name,
+        rowNumbers,
+        action: 'merged',
+        conflictingFields: [...(conflictsByKey.get(key) ?? [])],
+      })
+    }
+    const identity = importRowIdentity(row)
+    const group = rowNumbersByIdentity.get(identity) ?? { row, keys: 0, rowNumbers: [] }
+    group.keys += 1
+    group.rowNumbers.push(...rowNumbers)
+    rowNumbersByIdentity.set(identity, group)
+  }
+  for (const group of rowNumbersByIdentity.values()) {
+    if (group.keys > 1) {
+      duplicateWarnings.push({
+        scryfallId: group.row.scryfallId,
+        name: group.row.name,
+        rowNumbers: group.rowNumbers.sort((a, b) => a - b),
+        action: 'split',
+        conflictingFields: [],
+      })
+    }
+  }
+  duplicateWarnings.sort((a, b) => (a.rowNumbers[0] ?? 0) - (b.rowNumbers[0] ?? 0))
+
   return {
     rows: [...aggregate.values()],
     rowsImported,
@@ -432,5 +508,6 @@ export function transformDelimitedRowsToImport(
     rowsSkipped,
     skippedDetails,
     sourceRowNumbersByKey: Object.fromEntries(sourceRowNumbersByKey.entries()),
+    duplicateWarnings,
   }
 }
@@ -0,0 +1,119 @@
+// Decoder for the backend's compact IPC encoding: a MessagePack map
+// { fields: string[], rows: unknown[][] } with one array per row in field order.
+
+const textDecoder = new TextDecoder()
+
+class MsgpackReader {
+  private offset = 0
+  private readonly view: DataView
+  private readonly bytes: Uint8Array
+
+  constructor(buffer: ArrayBuffer | Uint8Array) {
+    this.bytes = buffer instanceof Uint8Array ? buffer : new Uint8Array(buffer)
+    this.view = new DataView(this.bytes.buffer, this.bytes.byteOffset, this.bytes.byteLength)
+  }
+
+  read(): unknown {
+    const byte = this.view.getUint8(this.offset++)
+    if (byte <= 0x7f) {
+      return byte
+    }
+    if (byte >= 0xe0) {
+      return byte - 0x100
+    }
+    if ((byte & 0xf0) === 0x80) {
+      return this.readMap(byte & 0x0f)
+    }
+    if ((byte & 0xf0) === 0x90) {
+      return this.readArray(byte & 0x0f)
+    }
+    if ((byte & 0xe0) === 0xa0) {
+      return this.readString(byte & 0x1f)
+    }
+
+    switch (byte) {
+      case 0xc0:
+        return null
+      case 0xc2:
+        return false
+      case 0xc3:
+        return true
+      case 0xca:
+        return this.take(4, (offset) => this.view.getFloat32(offset))
+      case 0xcb:
+        return this.take(8, (offset) => this.view.getFloat64(offset))
+      case 0xcc:
+        return this.take(1, (offset) => this.view.getUint8(offset))
+      case 0xcd:
+        return this.take(2, (offset) => this.view.getUint16(offset))
+      case 0xce:
+        return this.take(4, (offset) => this.view.getUint32(offset))
+      case 0xcf:
+        return this.take(8, (offset) => Number(this.view.getBigUint64(offset)))
+      case 0xd0:
+        return this.take(1, (offset) => this.view.getInt8(offset))
+      case 0xd1:
+        return this.take(2, (offset) => this.view.getInt16(offset))
+      case 0xd2:
+        return this.take(4, (offset) => this.view.getInt32(offset))
+      case 0xd3:
+        return this.take(8, (offset) => Number(this.view.getBigInt64(offset)))
+      case 0xd9:
+        return this.readString(this.take(1, (offset) => this.view.getUint8(offset)))
+      case 0xda:
+        return this.readString(this.take(2, (offset) => this.view.getUint16(offset)))
+      case 0xdb:
+        return this.readString(this.take(4, (offset) => this.view.getUint32(offset)))
+      case 0xdc:
+        return this.readArray(this.take(2, (offset) => this.view.getUint16(offset)))
+      case 0xdd:
+        return this.readArray(this.take(4, (offset) => this.view.getUint32(offset)))
+      case 0xde:
+        return this.readMap(this.take(2, (offset) => this.view.getUint16(offset)))
+      case 0xdf:
+        return this.readMap(this.take(4, (offset) => this.view.getUint32(offset)))
+      default:
+        throw new Error(`Unsupported MessagePack type 0x${byte.toString(16)}.`)
+    }
+  }
+
+  private take<T>(size: number, read: (offset: number) => T): T {
+    const value = read(this.offset)
+    this.offset += size
+    return value
+  }
+
+  private readString(length: number): string {
+    const value = textDecoder.decode(this.bytes.subarray(this.offset, this.offset + length))
+    this.offset += length
+    return value
+  }
+
+  private readArray(length: number): unknown[] {
+    const out = new Array<unknown>(length)
+    for (let index = 0; index < length; index += 1) {
+      out[index] = this.read()
+    }
+    return out
+  }
+
+  private readMap(length: number): Record<string, unknown> {
+    const out: Record<string, unknown> = {}
+    for (let index = 0; index < length; index += 1) {
+      const key = String(this.read())
+      out[key] = this.read()
+    }
+    return out
+  }
+}
+
+export function decodeCompactRows<T>(buffer: ArrayBuffer | Uint8Array): T[] {
+  const payload = new MsgpackReader(buffer).read() as { fields: string[]; rows: unknown[][] }
+  return payload.rows.map((row) => {
+    const out: Record<string, unknown> = {}
+    payload.fields.forEach((field, index) => {
+      out[field] = row[index]
+    })
+    return out as T
+  })
+}
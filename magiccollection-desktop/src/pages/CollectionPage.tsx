@@ -1,7 +1,17 @@
 import { startTransition, useEffect, useMemo, useRef, useState } from 'react'
 import type { KeyboardEvent, UIEvent } from 'react'
 import { createPortal } from 'react-dom'
-import { getCollectionPriceTrendsBySource, getFilterTokens, syncFilterTokens } from '../lib/backend'
+import {
+  autocompleteCardNames,
+  getCollectionPriceTrendsBySource,
+  getFilterTokens,
+  getIncludeDigitalPrintings,
+  getProfilePricePreferences,
+  listPriceChannels,
+  scryfallQueryForPrintings,
+  setProfilePricePreferences,
+  syncFilterTokens,
+} from '../lib/backend'
 import { ImportWizardModal } from '../components/ImportWizardModal'
 import type {
   AddCardInput,
@@ -120,6 +130,9 @@ interface ParsedSearchPlan {
   languages: string[]
   conditions: string[]
   foilMode: 'any' | 'foil' | 'nonfoil'
+  tradeMode: 'any' | 'trade' | 'keep'
+  playset: boolean
+  digitalMode: 'any' | 'digital' | 'paper'
   manaComparators: ManaComparator[]
 }
 
@@ -161,14 +174,15 @@ const DENSITY_IMAGE_MIN_WIDTH: Record<RowDensity, number> = {
   balanced: 170,
   dense: 158,
 }
-const PRICE_SOURCE_OPTIONS = [
-  { id: 'tcg-low', label: 'TCGplayer Low', providerId: 3, channelId: 6 },
-  { id: 'tcg-market', label: 'TCGplayer Market', providerId: 3, channelId: 4 },
-  { id: 'tcg-high', label: 'TCGplayer High', providerId: 3, channelId: 8 },
-  { id: 'ck-sell', label: 'CK Sell', providerId: 2, channelId: 11 },
-  { id: 'ck-buylist', label: 'CK Buylist', providerId: 2, channelId: 10 },
-] as const
-type PriceSourceId = (typeof PRICE_SOURCE_OPTIONS)[number]['id']
+type PriceSourceId = string
+interface PriceSourceOption {
+  id: PriceSourceId
+  label: string
+}
+// Fallback when the backend channel list is unavailable (browser preview).
+const DEFAULT_PRICE_SOURCE_OPTIONS: PriceSourceOption[] = [
+  { id: 'tcg-market', label: 'TCGplayer Market' },
+]
 const SEARCH_FIELD_PREFIXES = [
   'set:',
   'tag:',
@@ -249,6 +263,24 @@ function normalize(value: string): string {
   return value.trim().toLowerCase()
 }
 
+// Mirrors fold_card_name in the backend: lowercased, unaccented, whitespace-collapsed.
+function foldCardName(value: string): string {
+  return value
+    .normalize('NFD')
+    .replace(/[\u0300-\u036f]/g, '')
+    .toLowerCase()
+    .replace(/æ/g, 'ae')
+    .replace(/œ/g, 'oe')
+    .replace(/ß/g, 'ss')
+    .replace(/ø/g, 'o')
+    .replace(/ł/g, 'l')
+    .replace(/[đð]/g, 'd')
+    .replace(/ı/g, 'i')
+    .replace(/[\u2018\u2019]/g, "'")
+    .replace(/\s+/g, ' ')
+    .trim()
+}
+
 function visibleUserTags(tags: string[]): string[] {
   return tags.filter((tag) => !SYSTEM_TAGS.has(normalize(tag)))
 }
@@ -340,6 +372,9 @@ function parseSearchPlan(rawSearch: string): ParsedSearchPlan {
     languages: [],
     conditions: [],
     foilMode: 'any',
+    tradeMode: 'any',
+    playset: false,
+    digitalMode: 'any',
     manaComparators: [],
   }
   const terms = tokenizeSearchInput(rawSearch)
@@ -418,6 +453,22 @@ function parseSearchPlan(rawSearch: string): ParsedSearchPlan {
       plan.foilMode = 'nonfoil'
       continue
     }
+    if (term === 'is:trade' || term === 'is:keep') {
+      plan.tradeMode = term === 'is:trade' ? 'trade' : 'keep'
+      continue
+    }
+    if (term === 'is:playset') {
+      plan.playset = true
+      continue
+    }
+    if (term === 'is:digital' || term === 'not:digital') {
+      plan.digitalMode = term === 'is:digital' ? 'digital' : 'paper'
+      continue
+    }
+    // Sorting here comes from the table headers; sort: terms only order get_collection_page.
+    if (term.startsWith('sort:')) {
+      continue
+    }
     const manaMatch = term.match(/^mv(<=|>=|=|:|<|>)(\d+(?:\.\d+)?)$/)
     if (manaMatch) {
       const parsed = Number(manaMatch[2])
@@ -429,7 +480,7 @@ function parseSearchPlan(rawSearch: string): ParsedSearchPlan {
       }
       continue
     }
-    const nameTerm = term.startsWith('name:') ? term.slice(5).trim() : term
+    const nameTerm = foldCardName(term.startsWith('name:') ? term.slice(5) : term)
     if (nameTerm) {
       plan.freeText.push(nameTerm)
     }
@@ -459,7 +510,7 @@ function matchesSearchPlan(card: OwnedCard, plan: ParsedSearchPlan): boolean {
   const colorLabel = colorIdentityLabel(card.colorIdentity)
   const cardTagsNormalized = visibleUserTags(card.tags).map((tag) => normalize(tag))
   const searchable = [
-    card.name,
+    foldCardName(card.name),
     card.setCode,
     card.collectorNumber,
     typeName,
@@ -557,6 +608,15 @@ function matchesSearchPlan(card: OwnedCard, plan: ParsedSearchPlan): boolean {
   if (plan.foilMode === 'nonfoil' && card.quantity <= 0) {
     return false
   }
+  if (plan.tradeMode !== 'any' && (card.tradeStatus === 'trade') !== (plan.tradeMode === 'trade')) {
+    return false
+  }
+  if (plan.playset && card.quantity + card.foilQuantity < 4) {
+    return false
+  }
+  if (plan.digitalMode !== 'any' && Boolean(card.isDigital) !== (plan.digitalMode === 'digital')) {
+    return false
+  }
   if (plan.manaComparators.length > 0) {
     if (card.manaValue === null || card.manaValue === undefined) {
       return false
@@ -705,8 +765,9 @@ async function searchCardsForAddMenu(query: string): Promise<AddMenuCard[]> {
   if (!trimmed) {
     return []
   }
+  const includeDigital = await getIncludeDigitalPrintings()
   const url = `https://api.scryfall.com/cards/search?q=${encodeURIComponent(
-    trimmed,
+    scryfallQueryForPrintings(trimmed, includeDigital),
   )}&unique=prints&order=name&dir=asc`
   const response = await fetch(url)
   if (!response.ok) {
@@ -792,6 +853,9 @@ export function CollectionPage({
   const [sortColumn, setSortColumn] = useState<SortColumn>('total')
   const [sortDirection, setSortDirection] = useState<SortDirection>('desc')
   const [priceSource, setPriceSource] = useState<PriceSourceId>('tcg-market')
+  const [priceSourceOptions, setPriceSourceOptions] = useState<PriceSourceOption[]>(
+    DEFAULT_PRICE_SOURCE_OPTIONS,
+  )
   const [priceByMode, setPriceByMode] = useState<PriceByMode>('unit')
   const [sourceTrendById, setSourceTrendById] = useState<
     Record<
@@ -840,6 +904,7 @@ export function CollectionPage({
   const [addMenuResults, setAddMenuResults] = useState<AddMenuCard[]>([])
   const [isSearchingAddMenu, setIsSearchingAddMenu] = useState(false)
   const [addMenuError, setAddMenuError] = useState('')
+  const [addMenuNameSuggestions, setAddMenuNameSuggestions] = useState<string[]>([])
   const [foilModeByCard, setFoilModeByCard] = useState<Record<string, boolean>>({})
   const [activeTagByCard, setActiveTagByCard] = useState<Record<string, string>>({})
   const [selectedCardIds, setSelectedCardIds] = useState<Set<string>>(new Set())
@@ -885,24 +950,28 @@ export function CollectionPage({
     return trendForSource(card).currentPrice
   }
 
-  function displayPriceForCard(card: OwnedCard): number | null {
+  // Foil copies use the row's foil price when the trend comes from the row itself.
+  function positionValueForCard(card: OwnedCard): number | null {
     const unit = unitPriceForCard(card)
-    if (unit === null) {
+    if (sourceTrendById[card.scryfallId]) {
+      return unit === null ? null : unit * (card.quantity + card.foilQuantity)
+    }
+    const nonfoilUnit = card.nonfoilPrice ?? unit
+    const foilUnit = card.foilPrice ?? unit
+    if (nonfoilUnit === null && foilUnit === null) {
       return null
     }
+    return (nonfoilUnit ?? 0) * card.quantity + (foilUnit ?? 0) * card.foilQuantity
+  }
+
+  function displayPriceForCard(card: OwnedCard): number | null {
     if (priceByMode === 'position') {
-      return unit * (card.quantity + card.foilQuantity)
+      return positionValueForCard(card)
     }
-    return unit
+    return unitPriceForCard(card)
   }
 
-  const estimatedMarket = cards.reduce((sum, card) => {
-    const unit = unitPriceForCard(card)
-    if (unit === null) {
-      return sum
-    }
-    return sum + unit * (card.quantity + card.foilQuantity)
-  }, 0)
+  const estimatedMarket = cards.reduce((sum, card) => sum + (positionValueForCard(card) ?? 0), 0)
 
   const versionCountsByName = useMemo(() => {
     const map = new Map<string, Set<string>>()
@@ -1139,11 +1208,50 @@ export function CollectionPage({
     setIsHydratingTypeMetadata(false)
   }, [profileId])
 
+  useEffect(() => {
+    let cancelled = false
+    void listPriceChannels()
+      .then((channels) => {
+        if (cancelled || !channels.length) {
+          return
+        }
+        setPriceSourceOptions(
+          channels
+            .filter((channel) => channel.enabled)
+            .map((channel) => ({ id: channel.id, label: channel.label })),
+        )
+      })
+      .catch(() => undefined)
+    return () => {
+      cancelled = true
+    }
+  }, [])
+
+  useEffect(() => {
+    let cancelled = false
+    void getProfilePricePreferences(profileId)
+      .then((preferences) => {
+        if (!cancelled && preferences) {
+          setPriceSource(preferences.priceSourceId)
+        }
+      })
+      .catch(() => undefined)
+    return () => {
+      cancelled = true
+    }
+  }, [profileId])
+
+  // get_collection already prices cards with the profile's stored source; only fetch
+  // per-source trends when the loaded cards were priced with a different one.
+  const cardsMatchPriceSource = cards.every(
+    (card) => !card.priceSourceId || card.priceSourceId === priceSource,
+  )
+
   useEffect(() => {
     let cancelled = false
 
     async function loadSourcePrices() {
-      if (!cards.length) {
+      if (!cards.length || cardsMatchPriceSource) {
         setSourceTrendById({})
         return
       }
@@ -1186,7 +1294,7 @@ export function CollectionPage({
     return () => {
       cancelled = true
     }
-  }, [profileId, priceSource, cards.length])
+  }, [profileId, priceSource, cards.length, cardsMatchPriceSource])
 
   useEffect(() => {
     if (parsedSearchPlan.typeTerms.length <= 0) {
@@ -1390,6 +1498,23 @@ export function CollectionPage({
     })
   }, [cards])
 
+  // Plain names get suggestions; Scryfall syntax is left for the search itself.
+  useEffect(() => {
+    const prefix = addMenuQuery.trim()
+    if (modalMode !== 'add' || !prefix || /[:!<>="]/.test(prefix)) {
+      setAddMenuNameSuggestions([])
+      return
+    }
+    const timer = window.setTimeout(() => {
+      void autocompleteCardNames(prefix, 10)
+        .then((result) => setAddMenuNameSuggestions(result.names))
+        .catch(() => setAddMenuNameSuggestions([]))
+    }, 150)
+    return () => {
+      window.clearTimeout(timer)
+    }
+  }, [addMenuQuery, modalMode])
+
   useEffect(() => {
     const timer = window.setTimeout(() => {
       void syncFilterTokens(profileId).catch(() => {
@@ -2340,9 +2465,12 @@ export function CollectionPage({
                 onChange={(event) => {
                   const next = event.target.value as PriceSourceId
                   startTransition(() => setPriceSource(next))
+                  void setProfilePricePreferences({ profileId, priceSourceId: next }).catch(
+                    () => undefined,
+                  )
                 }}
               >
-                {PRICE_SOURCE_OPTIONS.map((option) => (
+                {priceSourceOptions.map((option) => (
                   <option key={option.id} value={option.id}>
                     {option.label}
                   </option>
@@ -2802,7 +2930,7 @@ export function CollectionPage({
                 <span>Sort: {SORT_COLUMN_LABELS[sortColumn]} ({sortDirection})</span>
                 <span>Density: {rowDensity}</span>
                 <span>Price By: {priceByMode === 'position' ? 'Total Position' : 'Unit Price'}</span>
-                <span>Source: {PRICE_SOURCE_OPTIONS.find((entry) => entry.id === priceSource)?.label ?? 'unknown'}</span>
+                <span>Source: {priceSourceOptions.find((entry) => entry.id === priceSource)?.label ?? 'unknown'}</span>
               </div>
             </div>
           )}
@@ -2918,7 +3046,13 @@ export function CollectionPage({
                           value={addMenuQuery}
                           onChange={(event) => setAddMenuQuery(event.target.value)}
                           placeholder='Search cards to add (e.g. name:"Sol Ring" or set:lea)'
+                          list="add-menu-name-suggestions"
                         />
+                        <datalist id="add-menu-name-suggestions">
+                          {addMenuNameSuggestions.map((name) => (
+                            <option key={name} value={name} />
+                          ))}
+                        </datalist>
                       </div>
                       <button className="button paw-pill" type="submit" disabled={isSearchingAddMenu}>
                         {isSearchingAddMenu ? 'Searching...' : 'Search'}
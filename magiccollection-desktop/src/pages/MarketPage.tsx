@@ -1,12 +1,21 @@
 import { useCallback, useEffect, useMemo, useRef, useState } from 'react'
 import type { FormEvent, KeyboardEvent } from 'react'
-import { getMarketPriceTrends, recordMarketSnapshots } from '../lib/backend'
+import {
+  getIncludeDigitalPrintings,
+  getMarketPriceTrends,
+  getPreviousOwnership,
+  recordMarketSnapshots,
+  resolveCardNames,
+  scryfallQueryForPrintings,
+} from '../lib/backend'
 import type {
   AddCardInput,
   MarketCard,
   MarketSnapshotInput,
   MarketTrend,
   OwnedCardMap,
+  PreviousOwnership,
+  PreviousSale,
   PriceDirection,
 } from '../types'
 
@@ -159,6 +168,16 @@ function formatUsd(value: number | null): string {
   return `$${value.toFixed(2)}`
 }
 
+function formatPreviousSale(sale: PreviousSale): string {
+  const price =
+    sale.currency === 'USD' ? formatUsd(sale.unitPrice) : `${sale.unitPrice.toFixed(2)} ${sale.currency}`
+  const change =
+    sale.changeSinceSale === null
+      ? ''
+      : ` (${sale.changeSinceSale >= 0 ? '+' : ''}${sale.changeSinceSale.toFixed(2)})`
+  return `Sold ${price} on ${sale.soldAt.slice(0, 10)} · now ${formatUsd(sale.currentPrice)}${change}`
+}
+
 export default function MarketPage({
   profileId,
   ownedCards,
@@ -174,6 +193,7 @@ export default function MarketPage({
   const [savedQueries, setSavedQueries] = useState<string[]>([])
   const [activeCard, setActiveCard] = useState<MarketCard | null>(null)
   const [queryWarning, setQueryWarning] = useState('')
+  const [previousOwnership, setPreviousOwnership] = useState<Record<string, PreviousOwnership>>({})
   const searchAbortRef = useRef<AbortController | null>(null)
   const debounceRef = useRef<number | null>(null)
 
@@ -258,8 +278,18 @@ export default function MarketPage({
     searchAbortRef.current = controller
 
     try {
+      // Plain-name searches go through the nickname table so "Bob" finds Dark Confidant.
+      let scryfallQuery = trimmedQuery
+      if (!/[:!<>="]/.test(trimmedQuery)) {
+        const [resolved] = await resolveCardNames([trimmedQuery])
+        if (resolved?.alias) {
+          scryfallQuery = `!"${resolved.name}"`
+          setQueryLabel(`${trimmedQuery} (${resolved.name})`)
+        }
+      }
+      const includeDigital = await getIncludeDigitalPrintings()
       const endpoint = `https://api.scryfall.com/cards/search?q=${encodeURIComponent(
-        trimmedQuery,
+        scryfallQueryForPrintings(scryfallQuery, includeDigital),
       )}&order=name&dir=asc&unique=prints`
       const response = await fetch(endpoint, { signal: controller.signal })
 
@@ -400,6 +430,31 @@ export default function MarketPage({
 
   const visibleResults = results.slice(0, visibleLimit)
 
+  // "You used to own this" for results with no owned copies; re-checked as copies come and go.
+  useEffect(() => {
+    const unownedIds = results
+      .map((card) => card.scryfallId)
+      .filter((id) => {
+        const owned = ownedCards[id]
+        return (owned?.quantity ?? 0) + (owned?.foilQuantity ?? 0) === 0
+      })
+    let cancelled = false
+    getPreviousOwnership(profileId, unownedIds)
+      .then((entries) => {
+        if (!cancelled) {
+          setPreviousOwnership(Object.fromEntries(entries.map((entry) => [entry.scryfallId, entry])))
+        }
+      })
+      .catch(() => {
+        if (!cancelled) {
+          setPreviousOwnership({})
+        }
+      })
+    return () => {
+      cancelled = true
+    }
+  }, [profileId, results, ownedCards])
+
   async function handleKeyboardAction(
     event: KeyboardEvent<HTMLElement>,
     card: MarketCard,
@@ -514,6 +569,7 @@ export default function MarketPage({
           const owned = ownedCards[card.scryfallId]
           const totalOwned = (owned?.quantity ?? 0) + (owned?.foilQuantity ?? 0)
           const foilOwned = owned?.foilQuantity ?? 0
+          const previous = totalOwned > 0 ? undefined : previousOwnership[card.scryfallId]
           const trendClass = `trend trend-${card.priceDirection}`
           const deltaText =
             card.priceDelta === null ? '--' : `${card.priceDelta >= 0 ? '+' : ''}${card.priceDelta.toFixed(2)}`
@@ -537,6 +593,10 @@ export default function MarketPage({
                       <span className="badge badge-foil">Foil {foilOwned}</span>
                     ) : null}
                   </div>
+                ) : previous ? (
+                  <div className="overlay-badges">
+                    <span className="badge badge-previous">Used to own</span>
+                  </div>
                 ) : null}
                 {card.imageUrl ? (
                   <img src={card.imageUrl} alt={card.name} loading="lazy" />
@@ -561,6 +621,9 @@ export default function MarketPage({
                     {trendGlyph(card.priceDirection)} {deltaText}
                   </span>
                 </div>
+                {previous?.lastSale ? (
+                  <p className="muted small">{formatPreviousSale(previous.lastSale)}</p>
+                ) : null}
 
                 <div className="tag-line">
                   {card.tags.length === 0 ? (
@@ -1,8 +1,17 @@
 import { useEffect, useState } from 'react'
+import {
+  getAutoHydrateAfterImport,
+  getDashboardFeedSettings,
+  getImagePrefetchStatus,
+  queueImagePrefetch,
+  setAutoHydrateAfterImport,
+  setDashboardFeedSettings,
+  setImagePrefetchWhenIdle,
+} from '../lib/backend'
 import { getCatalogSyncStatus, getSyncDiagnostics } from '../lib/catalogSync'
 import type { LocalAuthStatus } from '../lib/localAuth'
 import { clearPerfMetrics, getPerfMetrics } from '../lib/perfMetrics'
-import type { Profile } from '../types'
+import type { DashboardFeedStatus, ImagePrefetchStatus, Profile } from '../types'
 
 const ASSET_CREDITS = [
   {
@@ -47,6 +56,48 @@ export function SettingsPage({
   const [syncStatusLine, setSyncStatusLine] = useState('Loading sync state...')
   const [diagnostics, setDiagnostics] = useState(getSyncDiagnostics())
   const [perfMetrics, setPerfMetrics] = useState(getPerfMetrics(12))
+  const [autoHydrate, setAutoHydrate] = useState<boolean | null>(null)
+  const [imageCache, setImageCache] = useState<ImagePrefetchStatus | null>(null)
+  const [dashboardFeed, setDashboardFeed] = useState<DashboardFeedStatus | null>(null)
+
+  useEffect(() => {
+    let cancelled = false
+    void getAutoHydrateAfterImport().then((enabled) => {
+      if (!cancelled) {
+        setAutoHydrate(enabled)
+      }
+    })
+    void getImagePrefetchStatus().then((status) => {
+      if (!cancelled) {
+        setImageCache(status)
+      }
+    })
+    void getDashboardFeedSettings().then((status) => {
+      if (!cancelled) {
+        setDashboardFeed(status)
+      }
+    })
+    return () => {
+      cancelled = true
+    }
+  }, [])
+
+  async function toggleAutoHydrate(enabled: boolean) {
+    setAutoHydrate(await setAutoHydrateAfterImport(enabled))
+  }
+
+  async function toggleImagePrefetchWhenIdle(enabled: boolean) {
+    await setImagePrefetchWhenIdle(enabled)
+    setImageCache(await getImagePrefetchStatus())
+  }
+
+  async function updateDashboardFeed(input: { enabled: boolean; allowLan?: boolean; regenerateToken?: boolean }) {
+    setDashboardFeed(await setDashboardFeedSettings(input))
+  }
+
+  async function cacheProfileImages() {
+    setImageCache(await queueImagePrefetch({ profileId: activeProfile.id, startNow: true }))
+  }
 
   useEffect(() => {
     let cancelled = false
@@ -121,6 +172,91 @@ export function SettingsPage({
           </button>
         </article>
 
+        <article className="report-card">
+          <h3>Imports</h3>
+          <label>
+            <input
+              type="checkbox"
+              checked={autoHydrate ?? false}
+              disabled={autoHydrate === null}
+              onChange={(event) => void toggleAutoHydrate(event.target.checked)}
+            />{' '}
+            Hydrate metadata after import
+          </label>
+          <p className="muted">
+            Queues a background job that fetches card details and images for newly imported printings.
+          </p>
+        </article>
+
+        <article className="report-card">
+          <h3>Image Cache</h3>
+          <p className="muted">
+            {imageCache
+              ? `${imageCache.cached} cached (${(imageCache.cachedBytes / 1_048_576).toFixed(1)} MB) / ${imageCache.queued} queued / ${imageCache.failed} failed`
+              : 'Loading image cache...'}
+          </p>
+          <label>
+            <input
+              type="checkbox"
+              checked={imageCache?.whenIdle ?? false}
+              disabled={imageCache === null}
+              onChange={(event) => void toggleImagePrefetchWhenIdle(event.target.checked)}
+            />{' '}
+            Download queued images when idle
+          </label>
+          <p className="muted">
+            Images download a few at a time between other jobs, and pause when Scryfall asks the app to slow down.
+          </p>
+          <button className="button" onClick={() => void cacheProfileImages()} type="button">
+            Cache This Profile's Images
+          </button>
+        </article>
+
+        <article className="report-card">
+          <h3>Dashboard Feed</h3>
+          <p className="muted">
+            {dashboardFeed === null
+              ? 'Loading dashboard feed...'
+              : dashboardFeed.running
+                ? `Listening on ${dashboardFeed.address}`
+                : dashboardFeed.lastError ?? 'Off'}
+          </p>
+          <label>
+            <input
+              type="checkbox"
+              checked={dashboardFeed?.enabled ?? false}
+              disabled={dashboardFeed === null}
+              onChange={(event) => void updateDashboardFeed({ enabled: event.target.checked })}
+            />{' '}
+            Serve collection events to home dashboards
+          </label>
+          <label>
+            <input
+              type="checkbox"
+              checked={dashboardFeed?.allowLan ?? false}
+              disabled={!dashboardFeed?.enabled}
+              onChange={(event) => void updateDashboardFeed({ enabled: true, allowLan: event.target.checked })}
+            />{' '}
+            Allow other devices on the network
+          </label>
+          <p className="muted">
+            Home Assistant or Grafana can read /summary or subscribe to /events with the token below.
+          </p>
+          {dashboardFeed?.token ? (
+            <p className="muted">
+              Token: <code>{dashboardFeed.token}</code>
+            </p>
+          ) : null}
+          <button
+            className="button subtle"
+            onClick={() => void updateDashboardFeed({ enabled: true, regenerateToken: true })}
+            disabled={!dashboardFeed?.enabled}
+            type="button"
+          >
+            New Token
+          </button>
+        </article>
+
         <article className="report-card">
           <h3>Local Account</h3>
           <p className="muted">
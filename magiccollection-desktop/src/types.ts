@@ -21,6 +21,10 @@ export interface PriceTrend {
 }
 
 export interface OwnedCard {
+  // Backend owned row id; absent in the browser fallback.
+  ownedItemId?: string
+  locationId?: string | null
+  conditionId?: number
   scryfallId: string
   name: string
   setCode: string
@@ -30,6 +34,7 @@ export interface OwnedCard {
   colorIdentity?: string[]
   manaValue?: number | null
   rarity?: string | null
+  isDigital?: boolean
   quantity: number
   foilQuantity: number
   updatedAt: string
@@ -39,12 +44,26 @@ export interface OwnedCard {
   priceDelta: number | null
   priceDirection: PriceDirection
   lastPriceAt: string | null
+  // Latest price of each finish; foilQuantity copies are valued at foilPrice.
+  nonfoilPrice?: number | null
+  foilPrice?: number | null
+  priceSourceId?: string
   conditionCode: string
   language: string
   locationName?: string | null
   notes?: string | null
   purchasePrice?: number | null
+  // ISO 4217 code; null when purchasePrice is unset.
+  purchaseCurrency?: string | null
   dateAdded?: string | null
+  // Retail mode; absent in the browser fallback.
+  askingPrice?: number | null
+  tradeStatus?: TradeStatus | null
+  reservedQuantity?: number
+  reservedFoilQuantity?: number
+  // Set when imageUrl is a photo attachment; scryfallImageUrl keeps the catalog image.
+  scryfallImageUrl?: string | null
+  customImageId?: string | null
 }
 
 export type OwnedCardMap = Record<string, OwnedCard>
@@ -63,6 +82,9 @@ export interface AddCardInput {
   foil: boolean
   currentPrice?: number | null
   tags?: string[]
+  conditionCode?: string
+  language?: string
+  locationName?: string
 }
 
 export interface MarketCard {
@@ -94,6 +116,7 @@ export interface MarketSnapshotInput {
 
 export interface MarketTrend {
   scryfallId: string
+  foil: boolean
   currentPrice: number | null
   previousPrice: number | null
   priceDelta: number | null
@@ -101,6 +124,144 @@ export interface MarketTrend {
   lastPriceAt: string | null
 }
 
+export interface PricePoint {
+  capturedAt: string
+  price: number
+}
+
+export interface PriceChange {
+  days: number
+  // Last price captured at least `days` ago; null when the history is shorter than that.
+  previousPrice: number | null
+  change: number | null
+  changePercent: number | null
+}
+
+export interface PriceHistory {
+  scryfallId: string
+  sourceId: string
+  label: string
+  currency: string
+  days: number
+  // The series the points come from; NM nonfoil when the requested finish has no prices.
+  conditionCode: string
+  foil: boolean
+  // Oldest first, within the last `days` days.
+  points: PricePoint[]
+  currentPrice: number | null
+  lastPriceAt: string | null
+  minPrice: number | null
+  maxPrice: number | null
+  averagePrice: number | null
+  changes: PriceChange[]
+}
+
+export interface TopMover {
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  foil: boolean
+  copies: number
+  currentPrice: number
+  previousPrice: number
+  change: number
+  changeFormatted: string
+  changePercent: number | null
+  valueChange: number
+  valueChangeFormatted: string
+}
+
+// TCGplayer Market movers among owned printings over the last `windowDays` days.
+export interface TopMovers {
+  profileId: string
+  sourceId: string
+  currency: string
+  windowDays: number
+  since: string
+  byChange: TopMover[]
+  byPercent: TopMover[]
+}
+
+export interface BuylistArbitrageCandidate {
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  foil: boolean
+  // Owned copies not marked keep, and how many of them Card Kingdom will take.
+  copies: number
+  sellQuantity: number
+  quantityCap: number | null
+  buylistPrice: number
+  tcgLow: number | null
+  tcgMarket: number | null
+  // Buylist over the TCGplayer price in percent; negative when the buylist is lower.
+  spreadVsLow: number | null
+  spreadVsMarket: number | null
+  spread: number
+  expectedCash: number
+  expectedCashFormatted: string
+}
+
+// Card Kingdom sell candidates, best spread first.
+export interface BuylistArbitrage {
+  profileId: string
+  currency: string
+  minSpread: number
+  totalSellQuantity: number
+  totalExpectedCash: number
+  totalExpectedCashFormatted: string
+  candidates: BuylistArbitrageCandidate[]
+}
+
+// 'above' fires at or over the threshold, 'below' at or under it.
+export type PriceAlertDirection = 'above' | 'below'
+
+export interface PriceAlertInput {
+  profileId: string
+  scryfallId: string
+  // Defaults to the profile's price source.
+  sourceId?: string
+  direction: PriceAlertDirection
+  threshold: number
+  foil?: boolean
+}
+
+export interface PriceAlert {
+  id: string
+  profileId: string
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  sourceId: string
+  direction: PriceAlertDirection
+  threshold: number
+  foil: boolean
+  currentPrice: number | null
+  // Stays true until the price moves back past the threshold.
+  triggered: boolean
+  lastFiredAt: string | null
+  createdAt: string
+}
+
+// One firing; price syncs also emit these on the `price-alerts-fired` event.
+export interface PriceAlertEvent {
+  id: string
+  alertId: string
+  profileId: string
+  scryfallId: string
+  name: string
+  setCode: string
+  sourceId: string
+  direction: PriceAlertDirection
+  foil: boolean
+  threshold: number
+  price: number
+  firedAt: string
+}
+
 export interface CollectionImportRow {
   scryfallId: string
   name: string
@@ -119,6 +280,8 @@ export interface CollectionImportRow {
   language?: string
   notes?: string | null
   purchasePrice?: number | null
+  // ISO 4217 code; null when purchasePrice is unset.
+  purchaseCurrency?: string | null
   dateAdded?: string | null
 }
 
@@ -128,24 +291,1479 @@ export interface CollectionImportResult {
   rowsSkipped: number
 }
 
+export type CsvImportFormat =
+  | 'archidekt'
+  | 'delimited'
+  | 'moxfield'
+  | 'manabox'
+  | 'deckbox'
+  | 'tcgplayer'
+  | 'delver'
+  | 'cardcastle'
+  | 'pucatrade'
+
+export interface CsvImportSkippedRow {
+  rowNumber: number
+  reason: string
+  preview: string
+}
+
+// merge sums copies of rows that land on the same owned row; first and last keep one of them.
+export type ImportDuplicatePolicy = 'merge' | 'first' | 'last'
+
+export interface ImportDuplicateWarning {
+  scryfallId: string
+  name: string
+  rowNumbers: number[]
+  // split: same card under a different condition, language or location, imported as separate rows.
+  action: 'merged' | 'kept_first' | 'kept_last' | 'split'
+  // Fields set on several rows with different values.
+  conflictingFields: string[]
+}
+
+export interface CsvImportSummary extends CollectionImportResult {
+  format: CsvImportFormat
+  duplicatePolicy: ImportDuplicatePolicy
+  rowsRead: number
+  rowsMerged: number
+  chunksCommitted: number
+  // Capped sample; rowsSkipped has the full count.
+  skippedDetails: CsvImportSkippedRow[]
+  // Capped sample; duplicateWarningCount has the full count.
+  duplicateWarnings: ImportDuplicateWarning[]
+  duplicateWarningCount: number
+  // The background hydration job queued for the imported printings, when auto-hydration is on.
+  hydrationJobId: string | null
+  // Set when a row failed after earlier chunks were committed; the counts cover only those chunks.
+  stoppedAtRow: number | null
+  stopReason: string | null
+}
+
+export type DigitalPlatform = 'arena' | 'mtgo'
+
+// An Arena or MTGO import; it replaces the platform's holdings.
+export interface DigitalImportSummary {
+  platform: DigitalPlatform
+  rowsRead: number
+  rowsImported: number
+  copiesImported: number
+  rowsSkipped: number
+  printingCount: number
+  // Capped sample; rowsSkipped has the full count.
+  skippedDetails: CsvImportSkippedRow[]
+}
+
+export interface DigitalItem {
+  platform: DigitalPlatform
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  quantity: number
+  foilQuantity: number
+  importedAt: string
+}
+
+export interface DigitalPaperCard {
+  cardId: string
+  name: string
+  paperCopies: number
+  arenaCopies: number
+  mtgoCopies: number
+}
+
+// Cards by name across printings; basic lands are left out.
+export interface DigitalPaperReport {
+  profileId: string
+  platforms: DigitalPlatform[]
+  inBoth: DigitalPaperCard[]
+  digitalOnly: DigitalPaperCard[]
+  paperOnly: DigitalPaperCard[]
+}
+
+export type CollectionExportFormat = 'csv' | 'json'
+
+export type CollectionExportDialect = 'generic' | 'moxfield' | 'deckbox'
+
+export interface CollectionExport {
+  format: CollectionExportFormat
+  dialect: CollectionExportDialect
+  generatedAt: string
+  // CSV writes nonfoil and foil copies on separate lines.
+  rowCount: number
+  copyCount: number
+  outputPath: string
+}
+
+export interface ImportFormatDetection {
+  format: CsvImportFormat
+  delimiter: string
+  headers: string[]
+  // Set when the detected format cannot import this file.
+  error: string | null
+}
+
 export interface BulkTagRequest {
   profileId: string
   scryfallIds: string[]
+  // Collection search syntax resolved by the backend; replaces scryfallIds when set.
+  filterQuery?: string
   tags: string[]
   includeAutoRules: boolean
+  mode?: 'add' | 'remove' | 'replace'
+  // Explicit owned rows; used instead of scryfallIds when set.
+  ownedItemIds?: string[]
+  // Target every owned row of each scryfallId instead of the NM / en / no location row.
+  allRows?: boolean
+}
+
+export interface CollectionMutationResult {
+  cards: OwnedCard[]
+  affectedOwnedItemIds: string[]
 }
 
 export interface UpdateOwnedCardMetadataInput {
   profileId: string
   scryfallId: string
+  // Row to edit; defaults to the most recently updated row of scryfallId.
+  ownedItemId?: string
   conditionCode?: string
   language?: string
   locationName?: string
   notes?: string
   purchasePrice?: number | null
+  // Without it a price keeps the row's currency, else the profile default.
+  purchaseCurrency?: string
+  dateAdded?: string
+}
+
+export interface BulkOwnedCardTarget {
+  profileId: string
+  scryfallIds: string[]
+  // Explicit owned rows; used instead of scryfallIds when set.
+  ownedItemIds?: string[]
+  // Collection search syntax resolved by the backend; replaces scryfallIds when set.
+  filterQuery?: string
+}
+
+export interface BulkUpdateOwnedCardMetadataInput extends BulkOwnedCardTarget {
+  conditionCode?: string
+  language?: string
+  locationName?: string
+  notes?: string
+  purchasePrice?: number
+  purchaseCurrency?: string
   dateAdded?: string
 }
 
+export interface BulkMoveToLocationInput extends BulkOwnedCardTarget {
+  locationName?: string | null
+}
+
+// A profile tag with the rows and copies carrying it. Auto tags (owned, foil, playset) follow
+// quantities: they can be colored but not renamed, merged or deleted.
+export interface Tag {
+  tagId: string
+  name: string
+  colorHex: string | null
+  auto: boolean
+  rowCount: number
+  copyCount: number
+  createdAt: string
+}
+
+// A storage location with what it holds; totalValue uses the profile's price source.
+export interface Location {
+  locationId: string
+  name: string
+  kind: string
+  rowCount: number
+  copyCount: number
+  foilCount: number
+  totalValue: number
+  totalValueFormatted: string
+  insuredValueCap: number | null
+  createdAt: string
+  updatedAt: string
+}
+
+// An owned row kept at zero copies when the profile retains them; see setZeroQuantityRetention.
+export interface PreviouslyOwnedCard {
+  ownedItemId: string
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  imageUrl: string | null
+  conditionCode: string
+  language: string
+  locationName: string | null
+  notes: string | null
+  purchasePrice: number | null
+  purchaseCurrency: string | null
+  dateAdded: string | null
+  tags: string[]
+  updatedAt: string
+  lastSale: PreviousSale | null
+}
+
+export interface PreviousSale {
+  soldAt: string
+  // Per copy, in the sale's currency.
+  unitPrice: number
+  currency: string
+  soldQuantity: number
+  currentPrice: number | null
+  // Current price minus the sale price in USD.
+  changeSinceSale: number | null
+}
+
+export interface PreviousOwnership {
+  scryfallId: string
+  ownedItemId: string | null
+  lastSale: PreviousSale | null
+}
+
+export interface RemovedCard {
+  id: string
+  batchId: string
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  imageUrl: string | null
+  quantity: number
+  foilQuantity: number
+  conditionCode: string
+  language: string
+  locationName: string | null
+  notes: string | null
+  purchasePrice: number | null
+  purchaseCurrency: string | null
+  dateAdded: string | null
+  tags: string[]
+  removedAt: string
+  expiresAt: string
+}
+
+export interface OwnedRowTarget {
+  ownedItemId?: string
+  conditionCode?: string
+  language?: string
+  locationName?: string
+}
+
+export interface BackgroundStatus {
+  enabled: boolean
+  intervalMinutes: number
+  running: boolean
+  windowVisible: boolean
+  lastRunAt: string | null
+  nextRunAt: string | null
+  lastError: string | null
+  lastAlertCount: number
+}
+
+export interface PerformanceMetricSummary {
+  metricName: string
+  appVersion: string
+  runCount: number
+  avgMs: number
+  p95Ms: number
+  maxMs: number
+  rowsProcessed: number
+  slowCount: number
+  lastRecordedAt: string
+}
+
+export interface PerformanceMetricEntry {
+  metricKind: 'command' | 'query'
+  metricName: string
+  appVersion: string
+  durationMs: number
+  rowsProcessed: number | null
+  detail: string | null
+  recordedAt: string
+}
+
+export interface PerformanceMetrics {
+  since: string
+  appVersion: string
+  slowQueryMs: number
+  slowCommandMs: number
+  summaries: PerformanceMetricSummary[]
+  slowEntries: PerformanceMetricEntry[]
+}
+
+export interface OsNotificationSettings {
+  alerts: boolean
+  syncComplete: boolean
+  jobFailed: boolean
+}
+
+export interface PayloadArchiveSettings {
+  enabled: boolean
+  // Copies kept per vendor endpoint.
+  keep: number
+  archiveDir: string
+}
+
+export interface FullSourceSyncResult {
+  startedAt: string
+  finishedAt: string
+  syncVersion: string
+  scryfallScanned: number
+  scryfallUpdated: number
+  scryfallUnchanged: number
+  scryfallPriceSnapshots: number
+  tcgSetsScanned: number
+  tcgProductsMatched: number
+  tcgPriceUpserts: number
+  tcgSetsFailed: number
+  tcgMalformedRecords: number
+  ckScanned: number
+  ckUpsertedBuylist: number
+  ckUpsertedSell: number
+  ckMalformedRecords: number
+  scryfallSetsSynced: number
+  newPricedSets: number
+  syncWarnings: string[]
+}
+
+export type JobKind =
+  | 'full_sync'
+  | 'ck_sync'
+  | 'scryfall_sets'
+  | 'combo_sync'
+  | 'hydrate_metadata'
+  | 'fx_rates'
+  | 'demand_sync'
+  | 'image_prefetch'
+
+export type JobStatus = 'queued' | 'running' | 'completed' | 'failed' | 'cancelled'
+
+// Payload of the `jobs://updated` event and the job commands. `phase` is queued, started or done
+// plus kind-specific steps; `progress` and `result` depend on `kind`.
+export interface Job {
+  jobId: string
+  kind: JobKind
+  status: JobStatus
+  phase: string
+  createdAt: string
+  startedAt: string | null
+  finishedAt: string | null
+  cancelRequested: boolean
+  progress: unknown
+  result: unknown
+  error: string | null
+}
+
+// The local dashboard feed: GET /summary (JSON) and GET /events (server-sent events) on `address`,
+// authorized with `token` as a bearer token or ?token= parameter.
+export interface DashboardFeedStatus {
+  enabled: boolean
+  port: number
+  allowLan: boolean
+  token: string | null
+  running: boolean
+  address: string | null
+  lastError: string | null
+}
+
+// Card images in the on-disk cache and waiting for it. `job` is set when startNow queued or
+// joined an image_prefetch job.
+export interface ImagePrefetchStatus {
+  queued: number
+  cached: number
+  failed: number
+  cachedBytes: number
+  whenIdle: boolean
+  job: Job | null
+}
+
+// Progress of a running image_prefetch job.
+export interface ImagePrefetchProgress {
+  total: number
+  processed: number
+  cached: number
+  failed: number
+}
+
+// Result of an image_prefetch job. A rate-limited run stopped early; the rest stays queued.
+export interface ImagePrefetchResult {
+  attempted: number
+  cached: number
+  failed: number
+  remaining: number
+  rateLimited: boolean
+}
+
+export type SyncJobPhase =
+  | 'queued'
+  | 'started'
+  | 'tcgtracking'
+  | 'cardkingdom'
+  | 'scryfall_cards'
+  | 'scryfall_sets'
+  | 'finalizing'
+  | 'done'
+
+export interface SyncJobCounters {
+  // 0 until the TCGTracking set list has been read.
+  tcgSetsTotal: number
+  tcgSetsScanned: number
+  tcgProductsMatched: number
+  tcgPriceUpserts: number
+  ckScanned: number
+  scryfallScanned: number
+  scryfallUpdated: number
+  scryfallSetsSynced: number
+}
+
+// A full_sync job, as carried by the `sync://progress` event.
+export interface SyncJob extends Job {
+  kind: 'full_sync'
+  phase: SyncJobPhase
+  progress: SyncJobCounters | null
+  result: FullSourceSyncResult | null
+}
+
+export interface ArchivedPayload {
+  source: string
+  endpoint: string
+  capturedAt: string | null
+  truncated: boolean
+  filePath: string
+  compressedBytes: number
+}
+
+export interface AppDataLocation {
+  dataDir: string
+  dbPath: string
+  defaultDataDir: string
+  portable: boolean
+  portableDataDir: string | null
+  movedFiles: number
+}
+
+export interface Workspace {
+  path: string
+  name: string
+  isDefault: boolean
+  isCurrent: boolean
+  exists: boolean
+  lastOpenedAt: string | null
+}
+
+export interface Reservation {
+  id: string
+  ownedItemId: string
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  customerName: string
+  quantity: number
+  foilQuantity: number
+  note: string | null
+  status: 'active' | 'released' | 'sold'
+  saleId: string | null
+  createdAt: string
+  updatedAt: string
+}
+
+export interface SaleLineInput {
+  ownedItemId?: string
+  reservationId?: string
+  quantity?: number
+  foilQuantity?: number
+  unitPrice?: number
+}
+
+export interface SaleLine {
+  ownedItemId: string | null
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  conditionCode: string
+  quantity: number
+  foilQuantity: number
+  unitPrice: number
+  lineTotal: number
+  unitPriceFormatted: string
+  lineTotalFormatted: string
+  reservationId: string | null
+}
+
+export interface Sale {
+  id: string
+  customerName: string | null
+  note: string | null
+  currency: string
+  totalAmount: number
+  totalAmountFormatted: string
+  soldAt: string
+  lines: SaleLine[]
+}
+
+export interface SalesReport {
+  profileId: string
+  currency: string
+  saleCount: number
+  copiesSold: number
+  revenue: number
+  revenueFormatted: string
+  sales: Sale[]
+}
+
+// Money fields are in `currency`; purchase prices are converted at the rate of their purchase date.
+export interface ProfitLossLine {
+  ownedItemId: string
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  copies: number
+  purchasePrice: number
+  purchaseCurrency: string
+  purchaseDate: string
+  fxRate: number | null
+  fxRateDate: string | null
+  costBasis: number | null
+  marketValue: number | null
+  gain: number | null
+  gainFormatted: string | null
+}
+
+// Totals only cover lines with both a rate and a market price.
+export interface ProfitLossReport {
+  profileId: string
+  currency: string
+  priceSourceId: string
+  costBasis: number
+  costBasisFormatted: string
+  marketValue: number
+  marketValueFormatted: string
+  gain: number
+  gainFormatted: string
+  lineCount: number
+  missingRateCount: number
+  missingPriceCount: number
+  lines: ProfitLossLine[]
+}
+
+export interface PortfolioLine {
+  ownedItemId: string
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  copies: number
+  costBasis: number
+  marketValue: number
+  buylistValue: number | null
+  gain: number
+  gainFormatted: string
+  gainPercent: number | null
+}
+
+// Unrealized totals use TCGplayer Market; realized totals use the cost captured on each sale line.
+export interface PortfolioReport {
+  profileId: string
+  currency: string
+  costBasis: number
+  costBasisFormatted: string
+  marketValue: number
+  marketValueFormatted: string
+  unrealizedGain: number
+  unrealizedGainFormatted: string
+  buylistValue: number
+  buylistValueFormatted: string
+  buylistGain: number
+  buylistGainFormatted: string
+  heldLineCount: number
+  missingRateCount: number
+  missingPriceCount: number
+  proceeds: number
+  proceedsFormatted: string
+  realizedCostBasis: number
+  realizedCostBasisFormatted: string
+  realizedGain: number
+  realizedGainFormatted: string
+  copiesSold: number
+  missingSaleCostCount: number
+  totalGain: number
+  totalGainFormatted: string
+  topWinners: PortfolioLine[]
+  topLosers: PortfolioLine[]
+}
+
+export interface DemandSyncResult {
+  namesRequested: number
+  namesRanked: number
+  syncedAt: string
+}
+
+export interface FxRateSyncResult {
+  startDate: string
+  endDate: string
+  currencies: string[]
+  ratesUpserted: number
+  syncedAt: string
+}
+
+export interface PricingRuleSet {
+  baseSourceId?: string | null
+  markupPercent?: number
+  conditionDiscounts?: Record<string, number>
+  floor?: number | null
+  rounding?: 'none' | '99' | null
+}
+
+export interface PricingRules {
+  asking: PricingRuleSet
+  trade: PricingRuleSet
+}
+
+// Share of the NM price per condition code (NM, LP, MP, HP, DMG), 0 to 1.
+export interface ConditionMultipliers {
+  multipliers: Record<string, number>
+}
+
+// Price of a non-English copy relative to the English one, by finish and language code.
+export interface LanguagePremiums {
+  nonfoil: Record<string, number>
+  foil: Record<string, number>
+}
+
+export interface PricingPreview {
+  ownedItemId: string
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  conditionCode: string
+  foil: boolean
+  basePrice: number | null
+  askingPrice: number | null
+  tradeValue: number | null
+  manualAskingPrice: number | null
+}
+
+export interface InventoryFeedRequest {
+  profileId: string
+  format: 'shopify' | 'crystal'
+  sourceId?: string
+  markupPercent?: number
+  minimumPrice?: number
+  useAskingPrice?: boolean
+  tradeScope?: TradeScope
+  outputPath?: string
+}
+
+export interface InventoryFeed {
+  format: string
+  generatedAt: string
+  rowCount: number
+  copyCount: number
+  skippedUnpriced: number
+  outputPath: string | null
+  csv: string
+}
+
+export type SaleListingFormat = 'plain' | 'facebook' | 'reddit'
+
+export interface SaleListingTemplate {
+  format?: SaleListingFormat
+  title?: string
+  footer?: string
+  sourceId?: string
+  discountPercent?: number
+  bundleDiscountPercent?: number
+  minimumPrice?: number
+  useAskingPrice?: boolean
+  tradeScope?: TradeScope
+}
+
+export interface SaleListingLine {
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  conditionCode: string
+  language: string
+  foil: boolean
+  quantity: number
+  marketPrice: number | null
+  price: number
+  lineTotal: number
+  priceFormatted: string
+  lineTotalFormatted: string
+}
+
+export interface SaleListing {
+  profileId: string
+  format: SaleListingFormat
+  title: string
+  generatedAt: string
+  currency: string
+  priceSourceId: string
+  discountPercent: number
+  bundleDiscountPercent: number | null
+  copyCount: number
+  skippedUnpriced: number
+  skippedBelowMinimum: number
+  marketTotal: number
+  listingTotal: number
+  bundlePrice: number | null
+  listingTotalFormatted: string
+  bundlePriceFormatted: string | null
+  lines: SaleListingLine[]
+  text: string
+}
+
+export type ExportTemplateFormat = 'text' | 'csv' | 'html'
+
+export interface ExportTemplateColumn {
+  header: string
+  value: string
+}
+
+export interface ExportTemplate {
+  id: string
+  name: string
+  format: ExportTemplateFormat
+  columns: ExportTemplateColumn[]
+  headerTemplate: string | null
+  rowTemplate: string | null
+  footerTemplate: string | null
+  createdAt: string
+  updatedAt: string
+}
+
+export interface SaveExportTemplateInput {
+  profileId: string
+  templateId?: string
+  name: string
+  format: ExportTemplateFormat
+  columns?: ExportTemplateColumn[]
+  headerTemplate?: string
+  rowTemplate?: string
+  footerTemplate?: string
+}
+
+export interface RenderedExport {
+  templateId: string
+  name: string
+  format: ExportTemplateFormat
+  generatedAt: string
+  rowCount: number
+  copyCount: number
+  outputPath: string | null
+  content: string
+}
+
+export type PrintListSort = 'name' | 'set' | 'value'
+
+export interface PrintListOptions {
+  title?: string
+  fontSize?: number
+  sort?: PrintListSort
+  includeValue?: boolean
+  outputPath?: string
+}
+
+export interface PrintListLine {
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  quantity: number
+  value: number | null
+}
+
+export interface PrintList {
+  title: string
+  generatedAt: string
+  rowCount: number
+  copyCount: number
+  totalValue: number
+  currency: string
+  lines: PrintListLine[]
+  outputPath: string | null
+  html: string
+}
+
+export interface KitItemInput {
+  ownedItemId?: string
+  label?: string
+  quantity?: number
+  foilQuantity?: number
+}
+
+export interface KitItem {
+  id: string
+  ownedItemId: string | null
+  scryfallId: string | null
+  name: string
+  setCode: string | null
+  collectorNumber: string | null
+  quantity: number
+  foilQuantity: number
+  missingCopies: number
+  unitPrice: number | null
+  foilUnitPrice: number | null
+}
+
+export interface KitCheckout {
+  id: string
+  borrowerName: string
+  note: string | null
+  checkedOutAt: string
+  dueAt: string | null
+  checkedInAt: string | null
+  overdue: boolean
+}
+
+export interface Kit {
+  id: string
+  name: string
+  description: string | null
+  copyCount: number
+  totalValue: number
+  items: KitItem[]
+  checkout: KitCheckout | null
+}
+
+export type DeckBoard = 'commander' | 'main' | 'side'
+
+export interface DeckCard {
+  id: string
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  board: DeckBoard
+  quantity: number
+  ownedQuantity: number
+  missingQuantity: number
+  covered: boolean
+}
+
+export interface Deck {
+  id: string
+  name: string
+  format: string | null
+  description: string | null
+  cardCount: number
+  ownedCount: number
+  missingCount: number
+  cards: DeckCard[]
+  createdAt: string
+  updatedAt: string
+}
+
+export type DeckImportSource = 'moxfield' | 'archidekt' | 'tappedout' | 'file'
+
+export interface DeckExport {
+  generatedAt: string
+  lineCount: number
+  copyCount: number
+  outputPath: string
+}
+
+export interface DeckImportResult {
+  source: DeckImportSource
+  deck: Deck
+  // "<quantity> <name>" for each listed card that matched no printing.
+  unresolved: string[]
+}
+
+export interface OutOfHouseReport {
+  profileId: string
+  generatedAt: string
+  currency: string
+  kitCount: number
+  copyCount: number
+  totalValue: number
+  totalValueFormatted: string
+  overdueCount: number
+  kits: Kit[]
+}
+
+export interface Attachment {
+  id: string
+  ownedItemId: string
+  kind: 'photo'
+  filePath: string
+  url: string
+  originalName: string | null
+  mimeType: string | null
+  byteSize: number
+  caption: string | null
+  isPrimaryImage: boolean
+  createdAt: string
+}
+
+export interface ActivityFeedItem {
+  id: string
+  source: 'collection' | 'alert' | 'price_alert' | 'sync'
+  kind: string
+  occurredAt: string
+  title: string
+  detail: string | null
+  scryfallId: string | null
+  ownedItemId: string | null
+  payload: unknown
+  readAt: string | null
+}
+
+export interface ActivityFeed {
+  profileId: string
+  page: number
+  pageSize: number
+  hasMore: boolean
+  items: ActivityFeedItem[]
+}
+
+export interface CollectionTotals {
+  profileId: string
+  currency: string
+  totalCards: number
+  totalFoils: number
+  rowCount: number
+  priceSourceId: string
+  totalValue: number
+  totalValueFormatted: string
+  values: Array<{ channelId: string; label: string; totalValue: number; totalValueFormatted: string }>
+  sets: Array<{
+    setCode: string
+    setName: string | null
+    totalCards: number
+    totalFoils: number
+    rowCount: number
+  }>
+}
+
+export interface ValueSnapshot {
+  snapshotDate: string
+  marketValue: number
+  lowValue: number
+  buylistValue: number
+  purchaseCost: number
+  totalCards: number
+  capturedAt: string
+}
+
+export interface CollectionValueHistory {
+  profileId: string
+  currency: string
+  days: number
+  snapshots: ValueSnapshot[]
+}
+
+// Collection value counted in copies of a benchmark card, both at the profile's price source.
+export interface ValueInUnits {
+  profileId: string
+  priceSourceId: string
+  currency: string
+  collectionValue: number
+  collectionValueFormatted: string
+  benchmarkScryfallId: string
+  benchmarkName: string
+  benchmarkSetCode: string
+  benchmarkPrice: number
+  benchmarkPriceFormatted: string
+  units: number
+  // Shareable line, e.g. "My collection is worth 3.2 Gaea's Cradle (USG)."
+  summary: string
+}
+
+export interface FacetCount {
+  value: string
+  label: string
+  token: string | null
+  rowCount: number
+  copyCount: number
+}
+
+export interface CollectionFacets {
+  profileId: string
+  filter: string | null
+  rowCount: number
+  sets: FacetCount[]
+  colors: FacetCount[]
+  rarities: FacetCount[]
+  types: FacetCount[]
+  conditions: FacetCount[]
+  tags: FacetCount[]
+  locations: FacetCount[]
+}
+
+export type CollectionPageSortKey = 'name' | 'quantity' | 'price' | 'trend' | 'set'
+
+export interface CollectionPageInput {
+  profileId: string
+  // Ignored when a cursor is given.
+  offset?: number
+  limit?: number
+  // nextCursor of the previous page.
+  cursor?: string | null
+  sortKey?: CollectionPageSortKey
+  sortDirection?: 'asc' | 'desc'
+  filterQuery?: string
+  fields?: Array<keyof OwnedCard>
+}
+
+export interface CollectionPage {
+  rows: OwnedCard[]
+  totalCount: number
+  offset: number
+  limit: number
+  sortKey: CollectionPageSortKey
+  sortDirection: 'asc' | 'desc'
+  nextCursor: string | null
+}
+
+export interface CardAlias {
+  alias: string
+  cardName: string
+  isSeeded: boolean
+  updatedAt: string
+}
+
+// Set name or code as a scanning service or older export prints it, mapped to a set code.
+export interface SetAlias {
+  alias: string
+  setCode: string
+  isSeeded: boolean
+  updatedAt: string
+}
+
+export interface ResolvedCardName {
+  input: string
+  name: string
+  alias: string | null
+}
+
+export type CardSearchSource = 'card' | 'notes'
+
+export interface CardNameAutocomplete {
+  // 'scryfall' when nothing in the local catalog matched the prefix.
+  source: 'local' | 'scryfall'
+  names: string[]
+}
+
+export interface CardSearchHit {
+  source: CardSearchSource
+  cardId: string
+  scryfallId: string | null
+  name: string
+  typeLine: string | null
+  manaCost: string | null
+  imageUrl: string | null
+  ownedItemId: string | null
+  notes: string | null
+  // bm25 score; lower is a better match.
+  rank: number
+}
+
+export interface ComboSyncResult {
+  combos: number
+  pieces: number
+  skipped: number
+  version: string | null
+  syncedAt: string
+}
+
+export interface ComboPiece {
+  oracleId: string
+  cardName: string
+  owned: boolean
+}
+
+export interface ComboMatch {
+  comboId: string
+  description: string | null
+  produces: string[]
+  requires: string[]
+  colorIdentity: string | null
+  missingCount: number
+  pieces: ComboPiece[]
+}
+
+export interface ComboReport {
+  profileId: string
+  deckId: string | null
+  datasetVersion: string | null
+  syncedAt: string | null
+  completeCount: number
+  partialCount: number
+  truncated: boolean
+  combos: ComboMatch[]
+}
+
+export interface TextDiffSegment {
+  op: 'equal' | 'insert' | 'delete'
+  text: string
+}
+
+export interface OracleChange {
+  id: string
+  scryfallId: string
+  oracleId: string | null
+  name: string
+  previousText: string
+  currentText: string
+  changedAt: string
+  ownedQuantity: number
+  diff: TextDiffSegment[]
+}
+
+export interface ValuationGroup {
+  id: string
+  label: string
+  copyCount: number
+  totalValue: number
+  totalValueFormatted: string
+}
+
+export type TradeStatus = 'trade' | 'keep'
+
+export type TradeScope = 'all' | TradeStatus
+
+export interface CollectionValuation {
+  profileId: string
+  currency: string
+  priceSourceId: string
+  tradeScope: TradeScope
+  conditionAdjusted: boolean
+  copyCount: number
+  // Copies without a price in their own finish (a foil is never valued at the nonfoil price).
+  unpricedCopyCount: number
+  totalValue: number
+  totalValueFormatted: string
+  byDeck: ValuationGroup[]
+  byTag: ValuationGroup[]
+  byTradeStatus: ValuationGroup[]
+}
+
+export type InsuranceValuationMode = 'market' | 'replacement'
+
+export interface InsuranceReportLine {
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  conditionCode: string
+  foil: boolean
+  quantity: number
+  unitPrice: number | null
+  priceSourceId: string | null
+  totalValue: number
+  unitPriceFormatted: string | null
+  totalValueFormatted: string
+}
+
+// locationId null holds the copies without a location.
+export interface InsuranceLocation {
+  locationId: string | null
+  name: string | null
+  copyCount: number
+  unpricedCopyCount: number
+  totalValue: number
+  totalValueFormatted: string
+  insuredValueCap: number | null
+  insuredValueCapFormatted: string | null
+  overCap: boolean
+  uninsuredValue: number
+}
+
+export interface InsuranceReport {
+  profileId: string
+  mode: InsuranceValuationMode
+  tradeScope: TradeScope
+  currency: string
+  methodology: string
+  generatedAt: string
+  copyCount: number
+  unpricedCopyCount: number
+  totalValue: number
+  totalValueFormatted: string
+  lines: InsuranceReportLine[]
+  locations: InsuranceLocation[]
+  capWarnings: string[]
+}
+
+export interface TradeFodderRequest {
+  profileId: string
+  threshold?: number
+  includeLands?: boolean
+  protectedTags?: string[]
+  sourceId?: string
+}
+
+export interface TradeFodderCopy {
+  ownedItemId: string
+  scryfallId: string
+  setCode: string
+  collectorNumber: string
+  conditionCode: string
+  foil: boolean
+  quantity: number
+  unitPrice: number | null
+  unitPriceFormatted: string | null
+}
+
+export interface TradeFodderCard {
+  cardId: string
+  name: string
+  typeLine: string | null
+  ownedCount: number
+  protectedCount: number
+  surplusCount: number
+  totalValue: number
+  totalValueFormatted: string
+  copies: TradeFodderCopy[]
+}
+
+export interface TradeFodderReport {
+  profileId: string
+  threshold: number
+  includeLands: boolean
+  protectedTags: string[]
+  generatedAt: string
+  cardCount: number
+  copyCount: number
+  totalValue: number
+  totalValueFormatted: string
+  priceSourceId: string
+  currency: string
+  cards: TradeFodderCard[]
+}
+
+export type DemandRankSource = 'edhrec' | 'penny'
+
+export interface DemandReportRequest {
+  profileId: string
+  rankSource?: DemandRankSource
+  sourceId?: string
+}
+
+export interface DemandReportCard {
+  cardId: string
+  name: string
+  typeLine: string | null
+  rank: number | null
+  ownedCount: number
+  tradeCount: number
+  totalValue: number
+  totalValueFormatted: string
+  suggestion: 'sleeve' | 'keep' | 'sell'
+}
+
+export interface DemandReport {
+  profileId: string
+  rankSource: DemandRankSource
+  generatedAt: string
+  ranksUpdatedAt: string | null
+  rankedCount: number
+  unrankedCount: number
+  priceSourceId: string
+  currency: string
+  cards: DemandReportCard[]
+}
+
+export interface LanguageReportRequest {
+  profileId: string
+  sourceId?: string
+}
+
+export interface LanguageSummary {
+  language: string
+  rowCount: number
+  copyCount: number
+  foilCount: number
+  // Percent of all owned copies.
+  copyShare: number
+  englishValue: number
+  englishValueFormatted: string
+  estimatedValue: number
+  estimatedValueFormatted: string
+  premiumValue: number
+  premiumValueFormatted: string
+}
+
+export interface LanguagePremiumCard {
+  ownedItemId: string
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  language: string
+  conditionCode: string
+  foil: boolean
+  quantity: number
+  englishUnitPrice: number
+  foreignUnitPrice: number
+  premiumTotal: number
+  premiumTotalFormatted: string
+  // listed: the localized printing's own price; multiplier: the profile's language premium.
+  basis: 'listed' | 'multiplier'
+}
+
+export interface LanguageReport {
+  profileId: string
+  generatedAt: string
+  priceSourceId: string
+  currency: string
+  englishValue: number
+  englishValueFormatted: string
+  estimatedValue: number
+  estimatedValueFormatted: string
+  premiumValue: number
+  premiumValueFormatted: string
+  unpricedCopyCount: number
+  premiums: LanguagePremiums
+  languages: LanguageSummary[]
+  premiumCards: LanguagePremiumCard[]
+}
+
+export type PrintingUpgradePreference = 'oldest-frame' | 'cheapest' | 'sets'
+
+export interface PrintingUpgradeRequest {
+  profileId: string
+  preference?: PrintingUpgradePreference
+  // Preferred sets in priority order; required by the 'sets' preference.
+  setCodes?: string[]
+}
+
+export interface ChannelPrice {
+  channelId: string
+  label: string
+  unitPrice: number | null
+  unitPriceFormatted: string | null
+  totalValue: number
+  totalValueFormatted: string
+}
+
+export interface PrintingUpgradeLine {
+  cardId: string
+  name: string
+  scryfallId: string
+  setCode: string
+  collectorNumber: string
+  releasedAt: string | null
+  conditionCode: string
+  foil: boolean
+  quantity: number
+  // Sell lines only: the owned row given up and the printing that replaces it.
+  ownedItemId: string | null
+  replacementScryfallId: string | null
+  prices: ChannelPrice[]
+}
+
+export interface PrintingUpgradePlan {
+  profileId: string
+  preference: PrintingUpgradePreference
+  setCodes: string[]
+  generatedAt: string
+  currency: string
+  cardCount: number
+  copyCount: number
+  buy: PrintingUpgradeLine[]
+  sell: PrintingUpgradeLine[]
+  buyTotals: Array<{ channelId: string; label: string; totalValue: number; totalValueFormatted: string }>
+  sellTotals: Array<{ channelId: string; label: string; totalValue: number; totalValueFormatted: string }>
+}
+
+export type LimitedEventFormat = 'draft' | 'sealed' | 'cube' | 'other'
+
+export interface LimitedPoolCardInput {
+  scryfallId: string
+  quantity?: number
+  foilQuantity?: number
+}
+
+export interface LimitedPoolCard {
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  quantity: number
+  foilQuantity: number
+}
+
+export interface LimitedEvent {
+  id: string
+  format: LimitedEventFormat
+  name: string | null
+  setCode: string | null
+  eventDate: string | null
+  wins: number
+  losses: number
+  draws: number
+  archetype: string | null
+  notes: string | null
+  mergedAt: string | null
+  cardCount: number
+  cards: LimitedPoolCard[]
+  createdAt: string
+  updatedAt: string
+}
+
+export type QuantityReason = 'sold' | 'traded' | 'lost' | 'opened-from-pack' | 'correction' | 'audit'
+
+export type AuditLineStatus = 'pending' | 'match' | 'short' | 'over' | 'mismatch'
+
+export interface AuditLine {
+  id: string
+  scryfallId: string
+  name: string
+  setCode: string
+  collectorNumber: string
+  conditionCode: string
+  language: string
+  ownedItemId: string | null
+  systemQuantity: number
+  systemFoilQuantity: number
+  countedQuantity: number | null
+  countedFoilQuantity: number | null
+  quantityDelta: number
+  foilQuantityDelta: number
+  status: AuditLineStatus
+  countedAt: string | null
+}
+
+export interface Audit {
+  id: string
+  locationName: string | null
+  status: 'open' | 'reconciled'
+  notes: string | null
+  lineCount: number
+  countedLineCount: number
+  discrepancyCount: number
+  systemCopyCount: number
+  countedCopyCount: number
+  lines: AuditLine[]
+  reconciledAt: string | null
+  createdAt: string
+  updatedAt: string
+}
+
+export interface AuditCountInput {
+  profileId: string
+  auditId: string
+  lineId?: string
+  scryfallId?: string
+  conditionCode?: string
+  language?: string
+  quantity?: number
+  foilQuantity?: number
+  increment?: boolean
+}
+
 export interface FilterToken {
   token: string
   label: string